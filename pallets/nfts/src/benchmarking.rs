@@ -30,7 +30,7 @@ use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin as SystemOrigin};
 use sp_runtime::traits::{Bounded, One};
 
 use super::*;
-use crate::Pallet as Nfts;
+use crate::{features::attributes::MAX_COUNTED_ATTRIBUTES, Pallet as Nfts};
 
 const SEED: u32 = 0;
 
@@ -167,6 +167,19 @@ fn add_item_attribute<T: Config<I>, I: 'static>(
 	(key, caller, caller_lookup)
 }
 
+fn add_counted_item_attributes<T: Config<I>, I: 'static>(item: T::ItemId) {
+	for i in 0..MAX_COUNTED_ATTRIBUTES {
+		assert_ok!(Nfts::<T, I>::do_force_set_attribute(
+			None,
+			T::Helper::collection(0),
+			Some(item),
+			AttributeNamespace::CollectionOwner,
+			make_filled_vec(i as u16, T::KeyLimit::get() as usize).try_into().unwrap(),
+			vec![0; T::ValueLimit::get() as usize].try_into().unwrap(),
+		));
+	}
+}
+
 fn add_collection_attribute<T: Config<I>, I: 'static>(
 	i: u16,
 ) -> (BoundedVec<u8, T::KeyLimit>, T::AccountId, AccountIdLookupOf<T>) {
@@ -251,11 +264,21 @@ benchmarks_instance_pallet! {
 
 		let (collection, caller, _) = create_collection::<T, I>();
 		add_collection_metadata::<T, I>();
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 		for i in 0..m {
 			mint_item::<T, I>(i as u16);
 			add_item_metadata::<T, I>(T::Helper::item(i as u16));
 			lock_item::<T, I>(i as u16);
 			burn_item::<T, I>(i as u16);
+			// the metadata of a burned item, and its version, remain until the collection is
+			// destroyed
+			Nfts::<T, I>::set_metadata(
+				origin.clone(),
+				collection,
+				T::Helper::item(i as u16),
+				vec![0; T::StringLimit::get() as usize].try_into().unwrap(),
+			)?;
 		}
 		for i in 0..c {
 			mint_item::<T, I>(i as u16);
@@ -274,6 +297,8 @@ benchmarks_instance_pallet! {
 	mint {
 		let (collection, caller, caller_lookup) = create_collection::<T, I>();
 		let item = T::Helper::item(0);
+		Nfts::<T, I>::set_max_per_account(None, collection, Some(u32::MAX))?;
+		add_counted_item_attributes::<T, I>(item);
 	}: _(SystemOrigin::Signed(caller.clone()), collection, item, caller_lookup, None)
 	verify {
 		assert_last_event::<T, I>(Event::Issued { collection, item, owner: caller }.into());
@@ -282,6 +307,8 @@ benchmarks_instance_pallet! {
 	force_mint {
 		let (collection, caller, caller_lookup) = create_collection::<T, I>();
 		let item = T::Helper::item(0);
+		Nfts::<T, I>::set_max_per_account(None, collection, Some(u32::MAX))?;
+		add_counted_item_attributes::<T, I>(item);
 	}: _(SystemOrigin::Signed(caller.clone()), collection, item, caller_lookup, default_item_config())
 	verify {
 		assert_last_event::<T, I>(Event::Issued { collection, item, owner: caller }.into());
@@ -290,6 +317,8 @@ benchmarks_instance_pallet! {
 	burn {
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
+		add_item_metadata::<T, I>(item);
+		add_counted_item_attributes::<T, I>(item);
 	}: _(SystemOrigin::Signed(caller.clone()), collection, item)
 	verify {
 		assert_last_event::<T, I>(Event::Burned { collection, item, owner: caller }.into());
@@ -302,6 +331,7 @@ benchmarks_instance_pallet! {
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
 		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		Nfts::<T, I>::set_max_per_account(None, collection, Some(u32::MAX))?;
 	}: _(SystemOrigin::Signed(caller.clone()), collection, item, target_lookup)
 	verify {
 		assert_last_event::<T, I>(Event::Transferred { collection, item, from: caller, to: target }.into());
@@ -362,8 +392,10 @@ benchmarks_instance_pallet! {
 		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
 		let origin = SystemOrigin::Signed(target.clone()).into();
 		Nfts::<T, I>::set_accept_ownership(origin, Some(collection))?;
+		Nfts::<T, I>::propose_collection_owner(collection, target.clone(), None)?;
 	}: _(SystemOrigin::Signed(caller), collection, target_lookup)
 	verify {
+		assert!(!PendingCollectionOwner::<T, I>::contains_key(collection));
 		assert_last_event::<T, I>(Event::OwnerChanged { collection, new_owner: target }.into());
 	}
 
@@ -389,12 +421,14 @@ benchmarks_instance_pallet! {
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
 		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		Nfts::<T, I>::propose_collection_owner(collection, target.clone(), None)?;
 		let call = Call::<T, I>::force_collection_owner {
 			collection,
 			owner: target_lookup,
 		};
 	}: { call.dispatch_bypass_filter(origin)? }
 	verify {
+		assert!(!PendingCollectionOwner::<T, I>::contains_key(collection));
 		assert_last_event::<T, I>(Event::OwnerChanged { collection, new_owner: target }.into());
 	}
 
@@ -423,10 +457,11 @@ benchmarks_instance_pallet! {
 
 	set_attribute {
 		let key: BoundedVec<_, _> = vec![0u8; T::KeyLimit::get() as usize].try_into().unwrap();
-		let value: BoundedVec<_, _> = vec![0u8; T::ValueLimit::get() as usize].try_into().unwrap();
+		let value: BoundedVec<_, _> = vec![1u8; T::ValueLimit::get() as usize].try_into().unwrap();
 
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
+		add_item_attribute::<T, I>(item);
 	}: _(SystemOrigin::Signed(caller), collection, Some(item), AttributeNamespace::CollectionOwner, key.clone(), value.clone())
 	verify {
 		assert_last_event::<T, I>(
@@ -443,10 +478,11 @@ benchmarks_instance_pallet! {
 
 	force_set_attribute {
 		let key: BoundedVec<_, _> = vec![0u8; T::KeyLimit::get() as usize].try_into().unwrap();
-		let value: BoundedVec<_, _> = vec![0u8; T::ValueLimit::get() as usize].try_into().unwrap();
+		let value: BoundedVec<_, _> = vec![1u8; T::ValueLimit::get() as usize].try_into().unwrap();
 
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
+		add_item_attribute::<T, I>(item);
 	}: _(SystemOrigin::Root, Some(caller), collection, Some(item), AttributeNamespace::CollectionOwner, key.clone(), value.clone())
 	verify {
 		assert_last_event::<T, I>(
@@ -801,7 +837,7 @@ benchmarks_instance_pallet! {
 
 		let metadata = vec![0u8; T::StringLimit::get() as usize];
 		let mut attributes = vec![];
-		let attribute_value = vec![0u8; T::ValueLimit::get() as usize];
+		let attribute_value = vec![1u8; T::ValueLimit::get() as usize];
 		for i in 0..n {
 			let attribute_key = make_filled_vec(i as u16, T::KeyLimit::get() as usize);
 			attributes.push((attribute_key, attribute_value.clone()));
@@ -821,6 +857,7 @@ benchmarks_instance_pallet! {
 		let target: T::AccountId = account("target", 0, SEED);
 		T::Currency::make_free_balance_be(&target, DepositBalanceOf::<T, I>::max_value());
 		frame_system::Pallet::<T>::set_block_number(One::one());
+		add_counted_item_attributes::<T, I>(item);
 	}: _(SystemOrigin::Signed(target.clone()), Box::new(mint_data), signature, caller)
 	verify {
 		let metadata: BoundedVec<_, _> = metadata.try_into().unwrap();
@@ -829,12 +866,20 @@ benchmarks_instance_pallet! {
 
 	set_attributes_pre_signed {
 		let n in 0 .. T::MaxAttributesPerCall::get();
-		let (collection, _, _) = create_collection::<T, I>();
+		let (collection, caller, _) = create_collection::<T, I>();
 
 		let item_owner: T::AccountId = account("item_owner", 0, SEED);
 		let item_owner_lookup = T::Lookup::unlookup(item_owner.clone());
 
 		let (signer_public, signer) = T::Helper::signer();
+		let signer_lookup = T::Lookup::unlookup(signer.clone());
+		assert_ok!(Nfts::<T, I>::set_team(
+			SystemOrigin::Signed(caller).into(),
+			collection,
+			None,
+			Some(signer_lookup),
+			None,
+		));
 
 		T::Currency::make_free_balance_be(&item_owner, DepositBalanceOf::<T, I>::max_value());
 
@@ -847,8 +892,10 @@ benchmarks_instance_pallet! {
 			default_item_config(),
 		));
 
+		// replace existing values, so the counts of both the old and the new values are updated
+		add_counted_item_attributes::<T, I>(item);
 		let mut attributes = vec![];
-		let attribute_value = vec![0u8; T::ValueLimit::get() as usize];
+		let attribute_value = vec![1u8; T::ValueLimit::get() as usize];
 		for i in 0..n {
 			let attribute_key = make_filled_vec(i as u16, T::KeyLimit::get() as usize);
 			attributes.push((attribute_key, attribute_value.clone()));
@@ -857,7 +904,7 @@ benchmarks_instance_pallet! {
 			collection,
 			item,
 			attributes,
-			namespace: AttributeNamespace::Account(signer.clone()),
+			namespace: AttributeNamespace::CollectionOwner,
 			deadline: One::one(),
 		};
 		let message = Encode::encode(&pre_signed_data);
@@ -870,7 +917,7 @@ benchmarks_instance_pallet! {
 			Event::PreSignedAttributesSet {
 				collection,
 				item,
-				namespace: AttributeNamespace::Account(signer.clone()),
+				namespace: AttributeNamespace::CollectionOwner,
 			}
 			.into(),
 		);
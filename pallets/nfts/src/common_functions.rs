@@ -19,7 +19,8 @@
 
 use alloc::vec::Vec;
 
-use frame_support::pallet_prelude::*;
+use frame_support::{defensive, pallet_prelude::*};
+use frame_system::pallet_prelude::BlockNumberFor;
 
 use crate::*;
 
@@ -29,11 +30,79 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Item::<T, I>::get(collection, item).map(|i| i.owner)
 	}
 
+	/// Get the most recently minted item of the collection, if it wasn't burned since.
+	pub fn last_minted(collection: T::CollectionId) -> Option<T::ItemId> {
+		LastMinted::<T, I>::get(collection)
+	}
+
+	/// Returns whether an item has never changed owner since it was minted.
+	///
+	/// Returns `false` if the item doesn't exist.
+	///
+	/// NOTE: transfers are only counted from the block returned by
+	/// [`Self::transfers_tracked_since`], so an item transferred before then is still reported as
+	/// pristine.
+	pub fn is_pristine(collection: T::CollectionId, item: T::ItemId) -> bool {
+		Item::<T, I>::contains_key(collection, item) &&
+			ItemTransferCount::<T, I>::get(collection, item).is_zero()
+	}
+
+	/// Get the block from which item transfers are counted, or `None` if they were counted since
+	/// genesis.
+	pub fn transfers_tracked_since() -> Option<BlockNumberFor<T>> {
+		TransferTrackingSince::<T, I>::get()
+	}
+
 	/// Get the owner of the collection, if the collection exists.
 	pub fn collection_owner(collection: T::CollectionId) -> Option<T::AccountId> {
 		Collection::<T, I>::get(collection).map(|i| i.owner)
 	}
 
+	/// Exports the items of a collection, together with their owners and metadata.
+	///
+	/// - `collection`: The collection to export.
+	/// - `start_item`: The cursor returned by a previous call, or `None` to start from the
+	///   beginning.
+	/// - `limit`: The maximum number of items to read in this call.
+	///
+	/// Returns the exported `(item, owner, metadata)` tuples, along with the cursor to pass to the
+	/// next call if any items remain.
+	///
+	/// NOTE: items are exported in storage order, which is not the order of their IDs.
+	///
+	/// # Errors
+	///
+	/// This function returns a [`ZeroLimit`](crate::Error::ZeroLimit) error if `limit` is zero,
+	/// as no progress could be made and a `None` cursor would be mistaken for the end of the
+	/// export.
+	pub fn export_collection(
+		collection: T::CollectionId,
+		start_item: Option<T::ItemId>,
+		limit: u32,
+	) -> Result<CollectionExportOf<T, I>, DispatchError> {
+		ensure!(limit > 0, Error::<T, I>::ZeroLimit);
+		let mut iter = match start_item {
+			Some(item) => Item::<T, I>::iter_prefix_from(
+				collection,
+				Item::<T, I>::hashed_key_for(collection, item),
+			),
+			None => Item::<T, I>::iter_prefix(collection),
+		};
+		let items: Vec<_> = iter
+			.by_ref()
+			.take(limit as usize)
+			.map(|(item, details)| {
+				let metadata = ItemMetadataOf::<T, I>::get(collection, item).map(|m| m.data.into());
+				(item, details.owner, metadata)
+			})
+			.collect();
+		let cursor = match iter.next() {
+			Some(_) => items.last().map(|(item, ..)| *item),
+			None => None,
+		};
+		Ok((items, cursor))
+	}
+
 	/// Validates the signature of the given data with the provided signer's account ID.
 	///
 	/// # Errors
@@ -63,6 +132,98 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// Verifies an off-chain signed attestation about an item.
+	///
+	/// Checks that `signature` was produced by `signer` over the SCALE encoded
+	/// `(collection, item, attestation)` tuple, using [`Self::validate_signature`]. Nothing is
+	/// stored.
+	///
+	/// Returns `true` if the signature is valid, `false` otherwise.
+	pub fn verify_attestation(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		attestation: Vec<u8>,
+		signature: &T::OffchainSignature,
+		signer: &T::AccountId,
+	) -> bool {
+		let message = (collection, item, attestation).encode();
+		Self::validate_signature(&message, signature, signer).is_ok()
+	}
+
+	/// Ensures an account can receive one more item of a collection without exceeding the
+	/// collection's holding limit, see [`Self::set_max_per_account`].
+	///
+	/// # Errors
+	///
+	/// This function returns a [`HoldingLimitExceeded`](crate::Error::HoldingLimitExceeded) error
+	/// if the account already holds the maximum number of items allowed.
+	pub(crate) fn ensure_within_holding_limit(
+		collection: &T::CollectionId,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		if let Some(max_per_account) = MaxPerAccountOf::<T, I>::get(collection) {
+			ensure!(
+				who == &Self::escrow_account() ||
+					AccountBalance::<T, I>::get(collection, who) < max_per_account,
+				Error::<T, I>::HoldingLimitExceeded
+			);
+		}
+		Ok(())
+	}
+
+	/// Increments the number of items of a collection held by an account.
+	pub(crate) fn increment_account_balance(collection: &T::CollectionId, who: &T::AccountId) {
+		AccountBalance::<T, I>::mutate(collection, who, |balance| balance.saturating_inc());
+	}
+
+	/// Decrements the number of items of a collection held by an account, removing the record
+	/// once it reaches zero.
+	///
+	/// An account without a record is never expected to give up an item, so this is treated as a
+	/// defensive failure rather than silently ignored.
+	pub(crate) fn decrement_account_balance(collection: &T::CollectionId, who: &T::AccountId) {
+		AccountBalance::<T, I>::mutate_exists(collection, who, |maybe_balance| {
+			let balance = match maybe_balance {
+				Some(balance) => balance.saturating_sub(1),
+				None => {
+					defensive!("an account balance is decremented below zero");
+					0
+				},
+			};
+			*maybe_balance = (balance > 0).then_some(balance);
+		});
+	}
+
+	/// Returns the total amount of item and item metadata deposits locked in a collection.
+	///
+	/// - `collection`: The collection whose deposits are summed.
+	/// - `limit`: The maximum number of items which may be read.
+	///
+	/// # Errors
+	///
+	/// This function returns a dispatch error in the following cases:
+	/// - If the collection ID is invalid ([`UnknownCollection`](crate::Error::UnknownCollection)).
+	/// - If the collection has more than `limit` items
+	///   ([`LimitExceeded`](crate::Error::LimitExceeded)).
+	pub fn collection_total_deposit(
+		collection: T::CollectionId,
+		limit: u32,
+	) -> Result<DepositBalanceOf<T, I>, DispatchError> {
+		let details =
+			Collection::<T, I>::get(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		ensure!(details.items <= limit, Error::<T, I>::LimitExceeded);
+
+		let item_deposits = Item::<T, I>::iter_prefix_values(collection)
+			.fold(Zero::zero(), |total: DepositBalanceOf<T, I>, details| {
+				total.saturating_add(details.deposit.amount)
+			});
+		let metadata_deposits = ItemMetadataOf::<T, I>::iter_prefix_values(collection)
+			.fold(Zero::zero(), |total: DepositBalanceOf<T, I>, metadata| {
+				total.saturating_add(metadata.deposit.amount)
+			});
+		Ok(item_deposits.saturating_add(metadata_deposits))
+	}
+
 	pub(crate) fn set_next_collection_id(collection: T::CollectionId) {
 		let next_id = collection.increment();
 		NextCollectionId::<T, I>::set(next_id);
@@ -19,7 +19,10 @@
 //! The bitflag [`PalletFeature::Approvals`] needs to be set in [`Config::Features`] for NFTs
 //! to have the functionality defined in this module.
 
+use alloc::vec::Vec;
+
 use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::BlockNumberFor;
 
 use crate::*;
 
@@ -85,6 +88,41 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// Approves the transfer of many items of a collection to a single delegate.
+	///
+	/// This function is used to approve the transfer of each of the specified `items` to a
+	/// `delegate`, e.g. to grant a marketplace operator access to a set of items at once. Every
+	/// item must be owned by `owner`. Approvals are written through
+	/// [`Self::do_approve_transfer`], inside a storage layer so that either all of them are
+	/// written or, on any error, none of them are.
+	///
+	/// - `collection`: The identifier of the collection containing the items.
+	/// - `items`: The identifiers of the items to be approved.
+	/// - `owner`: The account required to be the owner of every item.
+	/// - `delegate`: The account that will be allowed to take control of the items.
+	/// - `maybe_deadline`: The optional deadline (in block numbers) specifying the time limit for
+	///   the approvals.
+	pub fn approve_many(
+		collection: T::CollectionId,
+		items: &[T::ItemId],
+		owner: T::AccountId,
+		delegate: T::AccountId,
+		maybe_deadline: Option<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		frame_support::storage::with_storage_layer(|| {
+			for item in items {
+				Self::do_approve_transfer(
+					Some(owner.clone()),
+					collection,
+					*item,
+					delegate.clone(),
+					maybe_deadline,
+				)?;
+			}
+			Ok(())
+		})
+	}
+
 	/// Cancels the approval for the transfer of an item to a delegate.
 	///
 	/// This function is used to cancel the approval for the transfer of the specified `item` in the
@@ -171,4 +209,34 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Ok(())
 	}
+
+	/// Returns the transfer approvals of an item which expire before a given block.
+	///
+	/// This function is used to find the approvals of the specified `item` in the `collection`
+	/// whose deadline is before `before_block`, e.g. so that wallets can warn about approvals
+	/// nearing expiry. Approvals without a deadline never expire and are not returned.
+	///
+	/// - `collection`: The collection ID containing the item.
+	/// - `item`: The item ID for which the approvals are read.
+	/// - `before_block`: The block number before which the returned approvals expire.
+	///
+	/// Returns the `(delegate, deadline)` pairs of the expiring approvals.
+	pub fn expiring_approvals(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		before_block: BlockNumberFor<T>,
+	) -> Vec<(T::AccountId, BlockNumberFor<T>)> {
+		Item::<T, I>::get(collection, item)
+			.map(|details| {
+				details
+					.approvals
+					.into_iter()
+					.filter_map(|(delegate, maybe_deadline)| match maybe_deadline {
+						Some(deadline) if deadline < before_block => Some((delegate, deadline)),
+						_ => None,
+					})
+					.collect()
+			})
+			.unwrap_or_default()
+	}
 }
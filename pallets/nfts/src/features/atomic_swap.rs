@@ -22,7 +22,7 @@
 
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement::KeepAlive},
+	traits::{tokens::nonfungibles_v2::Inspect, Currency, ExistenceRequirement::KeepAlive},
 };
 
 use crate::*;
@@ -233,4 +233,55 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Ok(())
 	}
+
+	/// Swaps two items between their owners in one atomic operation.
+	///
+	/// - `collection_a`: The collection of the item owned by `who_a`.
+	/// - `item_a`: The item owned by `who_a`, sent to `who_b`.
+	/// - `collection_b`: The collection of the item owned by `who_b`.
+	/// - `item_b`: The item owned by `who_b`, sent to `who_a`.
+	/// - `who_a`: The owner of `item_a`.
+	/// - `who_b`: The owner of `item_b`.
+	///
+	/// Unlike [`Self::do_claim_swap`], no swap needs to be created beforehand, so both owners
+	/// must have agreed to the swap. Both transfers are applied in a storage layer, so either
+	/// both items are transferred or neither is.
+	///
+	/// # Errors
+	///
+	/// This function returns a dispatch error in the following cases:
+	/// - If either item doesn't exist ([`UnknownItem`](crate::Error::UnknownItem)).
+	/// - If `who_a` doesn't own `item_a` or `who_b` doesn't own `item_b`
+	///   ([`NoPermission`](crate::Error::NoPermission)).
+	/// - If either item can't be transferred ([`ItemLocked`](crate::Error::ItemLocked)).
+	pub fn swap_items(
+		collection_a: T::CollectionId,
+		item_a: T::ItemId,
+		collection_b: T::CollectionId,
+		item_b: T::ItemId,
+		who_a: T::AccountId,
+		who_b: T::AccountId,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Swaps),
+			Error::<T, I>::MethodDisabled
+		);
+
+		let details_a =
+			Item::<T, I>::get(collection_a, item_a).ok_or(Error::<T, I>::UnknownItem)?;
+		let details_b =
+			Item::<T, I>::get(collection_b, item_b).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(details_a.owner == who_a, Error::<T, I>::NoPermission);
+		ensure!(details_b.owner == who_b, Error::<T, I>::NoPermission);
+		ensure!(
+			<Self as Inspect<T::AccountId>>::can_transfer(&collection_a, &item_a) &&
+				<Self as Inspect<T::AccountId>>::can_transfer(&collection_b, &item_b),
+			Error::<T, I>::ItemLocked
+		);
+
+		frame_support::storage::with_storage_layer(|| {
+			Self::do_transfer(collection_a, item_a, who_b, |_, _| Ok(()))?;
+			Self::do_transfer(collection_b, item_b, who_a, |_, _| Ok(()))
+		})
+	}
 }
@@ -20,10 +20,17 @@
 //! The bitflag [`PalletFeature::Attributes`] needs to be set in [`Config::Features`] for NFTs
 //! to have the functionality defined in this module.
 
-use frame_support::pallet_prelude::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::tokens::nonfungibles_v2::{Inspect, Mutate},
+};
 
 use crate::*;
 
+/// The maximum number of attributes in the `CollectionOwner` namespace an item may have when it's
+/// minted or burned, as the values of all of them are counted in [`AttributeValueCount`].
+pub(crate) const MAX_COUNTED_ATTRIBUTES: u32 = 20;
+
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Sets the attribute of an item or a collection.
 	///
@@ -67,6 +74,19 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Error::<T, I>::NoPermission
 		);
 
+		// for items in the `CollectionOwner` namespace we need to check the key is declared and the
+		// value matches its declared type if the collection has a strict schema
+		if namespace == AttributeNamespace::CollectionOwner && maybe_item.is_some() {
+			if let Some((schema, true)) = Self::schema(collection) {
+				let attr_type = schema
+					.iter()
+					.find(|(declared_key, _)| declared_key[..] == key[..])
+					.map(|(_, attr_type)| attr_type)
+					.ok_or(Error::<T, I>::UndeclaredAttribute)?;
+				ensure!(attr_type.is_valid(&value), Error::<T, I>::InvalidAttributeType);
+			}
+		}
+
 		let collection_config = Self::get_collection_config(&collection)?;
 		// for the `CollectionOwner` namespace we need to check if the collection/item is not locked
 		if namespace == AttributeNamespace::CollectionOwner {
@@ -94,8 +114,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			collection_details.attributes.saturating_inc();
 		}
 
-		let old_deposit =
-			attribute.map_or(AttributeDeposit { account: None, amount: Zero::zero() }, |m| m.1);
+		let (old_value, old_deposit) = attribute.map_or(
+			(None, AttributeDeposit { account: None, amount: Zero::zero() }),
+			|(value, deposit)| (Some(value), deposit),
+		);
 
 		let mut deposit = Zero::zero();
 		// disabled DepositRequired setting only affects the CollectionOwner namespace
@@ -146,6 +168,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			true => None,
 			false => Some(depositor),
 		};
+		Self::update_attribute_value_count(
+			&collection,
+			&maybe_item,
+			&namespace,
+			&key,
+			old_value.as_ref(),
+			Some(&value),
+		);
 		Attribute::<T, I>::insert(
 			(&collection, maybe_item, &namespace, &key),
 			(&value, AttributeDeposit { account: new_deposit_owner, amount: deposit }),
@@ -185,6 +215,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Collection::<T, I>::get(collection).ok_or(Error::<T, I>::UnknownCollection)?;
 
 		let attribute = Attribute::<T, I>::get((collection, maybe_item, &namespace, &key));
+		let old_value = attribute.as_ref().map(|(value, _)| value.clone());
 		if let Some((_, deposit)) = attribute {
 			if deposit.account != set_as && deposit.amount != Zero::zero() {
 				if let Some(deposit_account) = deposit.account {
@@ -194,6 +225,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		} else {
 			collection_details.attributes.saturating_inc();
 		}
+		Self::update_attribute_value_count(
+			&collection,
+			&maybe_item,
+			&namespace,
+			&key,
+			old_value.as_ref(),
+			Some(&value),
+		);
 
 		Attribute::<T, I>::insert(
 			(&collection, maybe_item, &namespace, &key),
@@ -288,8 +327,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		namespace: AttributeNamespace<T::AccountId>,
 		key: BoundedVec<u8, T::KeyLimit>,
 	) -> DispatchResult {
-		let (_, deposit) = Attribute::<T, I>::take((collection, maybe_item, &namespace, &key))
-			.ok_or(Error::<T, I>::AttributeNotFound)?;
+		let (old_value, deposit) =
+			Attribute::<T, I>::take((collection, maybe_item, &namespace, &key))
+				.ok_or(Error::<T, I>::AttributeNotFound)?;
 
 		if let Some(check_origin) = &maybe_check_origin {
 			// validate the provided namespace when it's not a root call and the caller is not
@@ -338,6 +378,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Collection::<T, I>::get(collection).ok_or(Error::<T, I>::UnknownCollection)?;
 
 		collection_details.attributes.saturating_dec();
+		Self::update_attribute_value_count(
+			&collection,
+			&maybe_item,
+			&namespace,
+			&key,
+			Some(&old_value),
+			None,
+		);
 
 		match deposit.account {
 			Some(deposit_account) => {
@@ -356,6 +404,58 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// Clears the attributes of an item whose keys start with a given prefix.
+	///
+	/// This function is intended for attributes using namespaced keys (e.g. `trait:color` and
+	/// `trait:size`), allowing all attributes under a prefix to be cleared at once. Each matching
+	/// attribute is cleared via [`Self::do_clear_attribute`], so the same permission and lock
+	/// checks apply and any deposit associated with it is unreserved. The attributes are cleared
+	/// in a storage layer, so if any of them fails none of them are cleared.
+	///
+	/// The attribute keys are stored hashed, so the prefix can't be used to seek into storage and
+	/// the keys of the namespace have to be read one by one. To keep the call bounded, at most
+	/// `limit` keys of the namespace are read, and only the ones among them starting with `prefix`
+	/// are cleared. A namespace holding more than `limit` attributes may therefore need a larger
+	/// `limit` for all of the attributes under the prefix to be cleared.
+	///
+	/// - `collection`: The identifier of the collection to which the item belongs.
+	/// - `item`: The identifier of the item whose attributes are being cleared.
+	/// - `prefix`: The prefix of the keys of the attributes to be cleared.
+	/// - `namespace`: The namespace of the attributes to be cleared.
+	/// - `limit`: The maximum number of attribute keys to be read, and so cleared, in this call.
+	/// - `maybe_check_origin`: An optional account clearing the attributes, e.g. an admin of the
+	///   collection for the `CollectionOwner` namespace. If `None`, it's considered the root
+	///   account.
+	///
+	/// Returns the number of attributes that were cleared.
+	pub fn clear_attributes_with_prefix(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		prefix: &[u8],
+		namespace: AttributeNamespace<T::AccountId>,
+		limit: u32,
+		maybe_check_origin: Option<T::AccountId>,
+	) -> Result<u32, DispatchError> {
+		let keys: Vec<_> =
+			Attribute::<T, I>::iter_key_prefix((&collection, Some(item), &namespace))
+				.take(limit as usize)
+				.filter(|key| key.starts_with(prefix))
+				.collect();
+		let cleared = keys.len() as u32;
+		frame_support::storage::with_storage_layer(|| {
+			for key in keys {
+				Self::do_clear_attribute(
+					maybe_check_origin.clone(),
+					collection,
+					Some(item),
+					namespace.clone(),
+					key,
+				)?;
+			}
+			Ok(cleared)
+		})
+	}
+
 	/// Approves a delegate to set attributes on behalf of the item's owner.
 	///
 	/// This function allows the owner of an item to approve a delegate to set attributes in the
@@ -501,6 +601,226 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(BoundedVec::try_from(value).map_err(|_| Error::<T, I>::IncorrectData)?)
 	}
 
+	/// A helper method to keep [`AttributeValueCount`] in sync when an attribute of an item in the
+	/// `CollectionOwner` namespace changes from `old_value` to `new_value`.
+	///
+	/// Attributes of collections, of items which don't exist (e.g. burned items) or in other
+	/// namespaces are not counted.
+	fn update_attribute_value_count(
+		collection: &T::CollectionId,
+		maybe_item: &Option<T::ItemId>,
+		namespace: &AttributeNamespace<T::AccountId>,
+		key: &BoundedVec<u8, T::KeyLimit>,
+		old_value: Option<&BoundedVec<u8, T::ValueLimit>>,
+		new_value: Option<&BoundedVec<u8, T::ValueLimit>>,
+	) {
+		let item_exists = match maybe_item {
+			Some(item) => Item::<T, I>::contains_key(collection, item),
+			None => false,
+		};
+		if !item_exists ||
+			namespace != &AttributeNamespace::CollectionOwner ||
+			old_value == new_value
+		{
+			return
+		}
+		if let Some(old_value) = old_value {
+			AttributeValueCount::<T, I>::mutate_exists((collection, key, old_value), |count| {
+				let new_count = count.unwrap_or_default().saturating_sub(1);
+				*count = (new_count > 0).then_some(new_count);
+			});
+		}
+		if let Some(new_value) = new_value {
+			AttributeValueCount::<T, I>::mutate((collection, key, new_value), |count| {
+				count.saturating_inc()
+			});
+		}
+	}
+
+	/// A helper method to add the values of an item's attributes in the `CollectionOwner`
+	/// namespace to [`AttributeValueCount`] once the item is minted, or to remove them once it is
+	/// burned, as only the attributes of existing items are counted.
+	///
+	/// - `collection`: The identifier of the collection to which the item belongs.
+	/// - `item`: The identifier of the item.
+	/// - `exists`: Whether the item was minted (`true`) or burned (`false`).
+	///
+	/// # Errors
+	///
+	/// This function returns a [`TooManyAttributes`](crate::Error::TooManyAttributes) error if
+	/// the item has more than [`MAX_COUNTED_ATTRIBUTES`] attributes in the `CollectionOwner`
+	/// namespace, in which case none of them are counted.
+	pub(crate) fn update_item_attribute_value_counts(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		exists: bool,
+	) -> DispatchResult {
+		let attributes: Vec<_> = Attribute::<T, I>::iter_prefix((
+			collection,
+			Some(*item),
+			AttributeNamespace::<T::AccountId>::CollectionOwner,
+		))
+		.take(MAX_COUNTED_ATTRIBUTES as usize + 1)
+		.collect();
+		ensure!(
+			attributes.len() <= MAX_COUNTED_ATTRIBUTES as usize,
+			Error::<T, I>::TooManyAttributes
+		);
+		for (key, (value, _)) in attributes {
+			AttributeValueCount::<T, I>::mutate_exists((collection, &key, &value), |count| {
+				let new_count = match exists {
+					true => count.unwrap_or_default().saturating_add(1),
+					false => count.unwrap_or_default().saturating_sub(1),
+				};
+				*count = (new_count > 0).then_some(new_count);
+			});
+		}
+		Ok(())
+	}
+
+	/// Computes the rarity score of an item from the attributes set in the `CollectionOwner`
+	/// namespace.
+	///
+	/// - `collection`: The identifier of the collection to which the item belongs.
+	/// - `item`: The identifier of the item to score.
+	///
+	/// Each of the item's attribute values scores `items * 1_000 / occurrences`, where `items` is
+	/// the number of items in the collection and `occurrences` the number of items having that
+	/// value set, and the item's score is the sum of them. Items with rarer values score higher.
+	///
+	/// Returns `None` if the item doesn't exist.
+	pub fn rarity_score(collection: T::CollectionId, item: T::ItemId) -> Option<u64> {
+		if !Item::<T, I>::contains_key(collection, item) {
+			return None
+		}
+		let items = Collection::<T, I>::get(collection)?.items as u64;
+		let score = Attribute::<T, I>::iter_prefix((
+			&collection,
+			Some(item),
+			AttributeNamespace::<T::AccountId>::CollectionOwner,
+		))
+		.map(|(key, (value, _))| {
+			let occurrences = AttributeValueCount::<T, I>::get((&collection, &key, &value)).max(1);
+			items.saturating_mul(1_000) / occurrences as u64
+		})
+		.fold(0u64, |score, value_score| score.saturating_add(value_score));
+		Some(score)
+	}
+
+	/// Declares the attribute schema of a collection.
+	///
+	/// - `collection`: The identifier of the collection.
+	/// - `schema`: The keys of the expected attributes, along with the types of their values.
+	/// - `strict`: Whether attributes with undeclared keys or values of the wrong type are
+	///   rejected.
+	/// - `maybe_check_origin`: An optional account ID which must be an admin of the collection. If
+	///   `None`, it's considered the root account.
+	///
+	/// The schema is stored as a collection attribute in the `Pallet` namespace, so its encoded
+	/// size is limited by `T::ValueLimit`. When the schema is strict, setting an item attribute in
+	/// the `CollectionOwner` namespace with an undeclared key fails with
+	/// [`UndeclaredAttribute`](crate::Error::UndeclaredAttribute), and with a value which isn't
+	/// valid for the declared [`AttrType`] fails with
+	/// [`InvalidAttributeType`](crate::Error::InvalidAttributeType).
+	pub fn set_schema(
+		collection: T::CollectionId,
+		schema: AttributeSchema,
+		strict: bool,
+		maybe_check_origin: Option<T::AccountId>,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, check_origin, CollectionRole::Admin),
+				Error::<T, I>::NoPermission
+			);
+		}
+		<Self as Mutate<T::AccountId, ItemConfig>>::set_typed_collection_attribute(
+			&collection,
+			&PalletAttributes::<T::CollectionId>::Schema,
+			&(schema, strict),
+		)
+	}
+
+	/// Returns the attribute schema declared by a collection and whether it's strict, if any.
+	pub fn schema(collection: T::CollectionId) -> Option<(AttributeSchema, bool)> {
+		<Self as Inspect<T::AccountId>>::typed_system_attribute(
+			&collection,
+			None,
+			&PalletAttributes::<T::CollectionId>::Schema,
+		)
+	}
+
+	/// Binds a collection to an external contract, so that tooling can discover it.
+	///
+	/// - `collection`: The identifier of the collection.
+	/// - `contract`: The account of the contract the collection is bound to.
+	/// - `maybe_check_owner`: An optional account ID which must be the owner of the collection. If
+	///   `None`, it's considered the root account.
+	///
+	/// The binding is stored as a collection attribute in the `Pallet` namespace, replacing any
+	/// previous one.
+	pub fn set_bound_contract(
+		collection: T::CollectionId,
+		contract: T::AccountId,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		if let Some(check_owner) = &maybe_check_owner {
+			let owner =
+				Self::collection_owner(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+			ensure!(check_owner == &owner, Error::<T, I>::NoPermission);
+		}
+		<Self as Mutate<T::AccountId, ItemConfig>>::set_typed_collection_attribute(
+			&collection,
+			&PalletAttributes::<T::CollectionId>::BoundContract,
+			&contract,
+		)
+	}
+
+	/// Returns the contract account a collection is bound to, if any.
+	pub fn bound_contract(collection: T::CollectionId) -> Option<T::AccountId> {
+		<Self as Inspect<T::AccountId>>::typed_system_attribute(
+			&collection,
+			None,
+			&PalletAttributes::<T::CollectionId>::BoundContract,
+		)
+	}
+
+	/// Returns the items of a collection owned by an account which have an attribute set to a
+	/// given value in the `CollectionOwner` namespace.
+	///
+	/// - `collection`: The identifier of the collection to which the items belong.
+	/// - `who`: The owner of the items.
+	/// - `key`: The key of the attribute.
+	/// - `value`: The value the attribute must be set to.
+	/// - `limit`: The maximum number of owned items which may be read.
+	///
+	/// NOTE: items are read in storage order, so when the owner holds more than `limit` items of
+	/// the collection, only a subset of them is inspected.
+	pub fn owned_with_attribute(
+		collection: T::CollectionId,
+		who: T::AccountId,
+		key: Vec<u8>,
+		value: Vec<u8>,
+		limit: u32,
+	) -> Vec<T::ItemId> {
+		let key = match Self::construct_attribute_key(key) {
+			Ok(key) => key,
+			Err(_) => return Vec::new(),
+		};
+		Account::<T, I>::iter_key_prefix((&who, &collection))
+			.take(limit as usize)
+			.filter(|item| {
+				Attribute::<T, I>::get((
+					&collection,
+					Some(*item),
+					AttributeNamespace::<T::AccountId>::CollectionOwner,
+					&key,
+				))
+				.is_some_and(|(attribute_value, _)| attribute_value[..] == value[..])
+			})
+			.collect()
+	}
+
 	/// A helper method to check whether a system attribute is set for a given item.
 	///
 	/// # Errors
@@ -22,8 +22,13 @@
 
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, ExistenceRequirement::KeepAlive},
+	traits::{
+		tokens::nonfungibles_v2::{Inspect, Mutate},
+		Currency, ExistenceRequirement,
+		ExistenceRequirement::KeepAlive,
+	},
 };
+use sp_runtime::Permill;
 
 use crate::*;
 
@@ -170,4 +175,133 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Ok(())
 	}
+
+	/// Returns the lowest and highest prices of the items listed for sale in a collection.
+	///
+	/// - `collection`: The identifier of the collection containing the listed items.
+	/// - `limit`: The maximum number of listings which may be read.
+	///
+	/// NOTE: listings are read in storage order, so when the collection has more than `limit`
+	/// listings, only a subset of them is inspected.
+	///
+	/// Returns `Some((floor, ceiling))`, or `None` if no item is listed.
+	pub fn price_range(
+		collection: T::CollectionId,
+		limit: u32,
+	) -> Option<(ItemPrice<T, I>, ItemPrice<T, I>)> {
+		ItemPriceOf::<T, I>::iter_prefix_values(collection).take(limit as usize).fold(
+			None,
+			|range, (price, _)| match range {
+				None => Some((price, price)),
+				Some((floor, ceiling)) => Some((floor.min(price), ceiling.max(price))),
+			},
+		)
+	}
+
+	/// Sets the royalty paid to a recipient on the sales of a collection's items.
+	///
+	/// - `collection`: The identifier of the collection.
+	/// - `recipient`: The account receiving the royalty.
+	/// - `basis_points`: The share of the sale price paid as royalty, in basis points.
+	/// - `maybe_check_owner`: An optional account ID which must be the owner of the collection. If
+	///   `None`, it's considered the root account.
+	///
+	/// The royalty is stored as a collection attribute in the `Pallet` namespace and is paid by
+	/// [`Self::sell_item`].
+	pub fn set_collection_royalty(
+		collection: T::CollectionId,
+		recipient: T::AccountId,
+		basis_points: u16,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		ensure!(basis_points <= 10_000, Error::<T, I>::InvalidBasisPoints);
+		if let Some(check_owner) = &maybe_check_owner {
+			let owner =
+				Self::collection_owner(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+			ensure!(check_owner == &owner, Error::<T, I>::NoPermission);
+		}
+		<Self as Mutate<T::AccountId, ItemConfig>>::set_typed_collection_attribute(
+			&collection,
+			&PalletAttributes::<T::CollectionId>::Royalty,
+			&(recipient, basis_points),
+		)
+	}
+
+	/// Returns the royalty recipient and basis points of a collection, if set.
+	pub fn collection_royalty(collection: T::CollectionId) -> Option<(T::AccountId, u16)> {
+		<Self as Inspect<T::AccountId>>::typed_system_attribute(
+			&collection,
+			None,
+			&PalletAttributes::<T::CollectionId>::Royalty,
+		)
+	}
+
+	/// Sells an item, paying the royalty and the marketplace fee out of the sale price.
+	///
+	/// This function is used by marketplaces to settle a sale in one atomic operation. The item
+	/// must be listed for sale at or below `price`, and to the `buyer` if the listing has a
+	/// whitelisted buyer. The `buyer` pays the royalty of the item (if any) to its recipient, the
+	/// marketplace fee to `marketplace_account` and the remainder to the `seller`, after which
+	/// the item is transferred to the `buyer`. The payments and the transfer are applied in a
+	/// storage layer, so on any error none of them are.
+	///
+	/// - `collection`: The identifier of the collection containing the item.
+	/// - `item`: The identifier of the item being sold.
+	/// - `seller`: The account selling the item, which must be its owner.
+	/// - `buyer`: The account buying the item.
+	/// - `price`: The sale price of the item.
+	/// - `marketplace_fee_bps`: The share of the price paid to the marketplace, in basis points.
+	/// - `marketplace_account`: The account receiving the marketplace fee.
+	///
+	/// Emits `ItemBought` event upon success.
+	pub fn sell_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		seller: T::AccountId,
+		buyer: T::AccountId,
+		price: ItemPrice<T, I>,
+		marketplace_fee_bps: u16,
+		marketplace_account: T::AccountId,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Trading),
+			Error::<T, I>::MethodDisabled
+		);
+
+		let details = Item::<T, I>::get(collection, item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(details.owner == seller, Error::<T, I>::NoPermission);
+		ensure!(seller != buyer, Error::<T, I>::NoPermission);
+
+		let price_info =
+			ItemPriceOf::<T, I>::get(collection, item).ok_or(Error::<T, I>::NotForSale)?;
+		ensure!(price >= price_info.0, Error::<T, I>::BidTooLow);
+		if let Some(only_buyer) = price_info.1 {
+			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
+		}
+
+		let royalty = Self::royalty_of(&collection, &item);
+		let royalty_bps = royalty.as_ref().map_or(0, |(_, basis_points)| *basis_points);
+		ensure!(
+			u32::from(royalty_bps).saturating_add(marketplace_fee_bps.into()) <= 10_000,
+			Error::<T, I>::InvalidBasisPoints
+		);
+		let share = |basis_points: u16| Permill::from_parts(u32::from(basis_points) * 100) * price;
+		let marketplace_fee = share(marketplace_fee_bps);
+
+		frame_support::storage::with_storage_layer(|| {
+			let mut remainder = price.saturating_sub(marketplace_fee);
+			if let Some((recipient, basis_points)) = royalty {
+				let royalty_amount = share(basis_points);
+				remainder = remainder.saturating_sub(royalty_amount);
+				T::Currency::transfer(&buyer, &recipient, royalty_amount, KeepAlive)?;
+			}
+			T::Currency::transfer(&buyer, &marketplace_account, marketplace_fee, KeepAlive)?;
+			T::Currency::transfer(&buyer, &seller, remainder, KeepAlive)?;
+
+			Self::do_transfer(collection, item, buyer.clone(), |_, _| Ok(()))?;
+
+			Self::deposit_event(Event::ItemBought { collection, item, price, seller, buyer });
+			Ok(())
+		})
+	}
 }
@@ -127,6 +127,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			}
 
 			CollectionMetadataOf::<T, I>::remove(collection);
+			CollectionMetadataVersion::<T, I>::remove(collection);
 			Self::clear_roles(&collection)?;
 
 			for (_, (_, deposit)) in Attribute::<T, I>::drain_prefix((&collection,)) {
@@ -140,7 +141,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			CollectionAccount::<T, I>::remove(&collection_details.owner, collection);
 			T::Currency::unreserve(&collection_details.owner, collection_details.owner_deposit);
 			CollectionConfigOf::<T, I>::remove(collection);
+			MaxPerAccountOf::<T, I>::remove(collection);
+			LastMinted::<T, I>::remove(collection);
+			PendingCollectionOwner::<T, I>::remove(collection);
 			let _ = ItemConfigOf::<T, I>::clear_prefix(collection, witness.item_configs, None);
+			// only items with a config can have had their metadata set
+			let _ =
+				ItemMetadataVersion::<T, I>::clear_prefix(collection, witness.item_configs, None);
+			let _ =
+				AttributeValueCount::<T, I>::clear_prefix((collection,), witness.attributes, None);
 
 			Self::deposit_event(Event::Destroyed { collection });
 
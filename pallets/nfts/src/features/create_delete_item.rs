@@ -41,6 +41,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	///   ([`InconsistentItemConfig`](crate::Error::InconsistentItemConfig)).
 	/// - If the max supply limit (if configured) for the collection is reached
 	///   ([`MaxSupplyReached`](crate::Error::MaxSupplyReached)).
+	/// - If the current block is outside of the collection's mint window (if configured)
+	///   ([`MintWindowClosed`](crate::Error::MintWindowClosed)).
+	/// - If the holding limit (if configured) for `mint_to` is reached
+	///   ([`HoldingLimitExceeded`](crate::Error::HoldingLimitExceeded)).
+	/// - If the item already has more than
+	///   [`MAX_COUNTED_ATTRIBUTES`](crate::features::attributes::MAX_COUNTED_ATTRIBUTES) attributes
+	///   in the `CollectionOwner` namespace
+	///   ([`TooManyAttributes`](crate::Error::TooManyAttributes)).
 	/// - If any error occurs in the `with_details_and_config` closure.
 	pub fn do_mint(
 		collection: T::CollectionId,
@@ -62,9 +70,18 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			let collection_config = Self::get_collection_config(&collection)?;
 			with_details_and_config(collection_details, &collection_config)?;
 
+			let now = frame_system::Pallet::<T>::block_number();
+			let mint_settings = &collection_config.mint_settings;
+			ensure!(
+				mint_settings.start_block.is_none_or(|start| start <= now) &&
+					mint_settings.end_block.is_none_or(|end| now <= end),
+				Error::<T, I>::MintWindowClosed
+			);
+
 			if let Some(max_supply) = collection_config.max_supply {
 				ensure!(collection_details.items < max_supply, Error::<T, I>::MaxSupplyReached);
 			}
+			Self::ensure_within_holding_limit(&collection, &mint_to)?;
 
 			collection_details.items.saturating_inc();
 
@@ -81,6 +98,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 			let item_owner = mint_to.clone();
 			Account::<T, I>::insert((&item_owner, &collection, &item), ());
+			Self::increment_account_balance(&collection, &item_owner);
 
 			if let Ok(existing_config) = ItemConfigOf::<T, I>::try_get(collection, item) {
 				ensure!(existing_config == item_config, Error::<T, I>::InconsistentItemConfig);
@@ -98,6 +116,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				deposit,
 			};
 			Item::<T, I>::insert(collection, item, details);
+			LastMinted::<T, I>::insert(collection, item);
+			Self::update_item_attribute_value_counts(&collection, &item, true)?;
 			Ok(())
 		})?;
 
@@ -202,6 +222,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// This function returns a dispatch error in the following cases:
 	/// - If the collection ID is invalid ([`UnknownCollection`](crate::Error::UnknownCollection)).
 	/// - If the item is locked ([`ItemLocked`](crate::Error::ItemLocked)).
+	/// - If the item has more than
+	///   [`MAX_COUNTED_ATTRIBUTES`](crate::features::attributes::MAX_COUNTED_ATTRIBUTES) attributes
+	///   in the `CollectionOwner` namespace
+	///   ([`TooManyAttributes`](crate::Error::TooManyAttributes)), which need to be cleared first.
 	pub fn do_burn(
 		collection: T::CollectionId,
 		item: T::ItemId,
@@ -255,10 +279,19 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		)?;
 
 		Item::<T, I>::remove(collection, item);
+		Self::update_item_attribute_value_counts(&collection, &item, false)?;
 		Account::<T, I>::remove((&owner, &collection, &item));
+		Self::decrement_account_balance(&collection, &owner);
 		ItemPriceOf::<T, I>::remove(collection, item);
 		PendingSwapOf::<T, I>::remove(collection, item);
 		ItemAttributesApprovalsOf::<T, I>::remove(collection, item);
+		ItemEscrowOf::<T, I>::remove(collection, item);
+		PendingGiftOf::<T, I>::remove(collection, item);
+		ItemTransferCount::<T, I>::remove(collection, item);
+		ItemMetadataVersion::<T, I>::remove(collection, item);
+		if LastMinted::<T, I>::get(collection) == Some(item) {
+			LastMinted::<T, I>::remove(collection);
+		}
 
 		if remove_config {
 			ItemConfigOf::<T, I>::remove(collection, item);
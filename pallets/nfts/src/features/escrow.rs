@@ -0,0 +1,217 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains helper methods to hold items in the pallet's escrow account, e.g. for
+//! trustless swaps or time-locked gifts.
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::AccountIdConversion;
+
+use crate::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The account holding escrowed items, derived from [`Config::PalletId`].
+	pub fn escrow_account() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Transfers an item into the pallet's escrow account.
+	///
+	/// - `collection`: The ID of the collection to which the item belongs.
+	/// - `item`: The ID of the item to be escrowed.
+	/// - `who`: The owner of the item, recorded as the depositor of the escrow.
+	///
+	/// The item is transferred to [`Self::escrow_account`] and can only be released by the
+	/// depositor or an admin of the collection, see [`Self::release_escrow`].
+	///
+	/// Emits `ItemEscrowed` event upon success.
+	/// Returns `Ok(())` on success, or one of the following dispatch errors:
+	/// - `UnknownItem`: The specified item does not exist.
+	/// - `NoPermission`: `who` is not the owner of the item.
+	/// - Any error returned by [`Self::do_transfer`].
+	pub fn escrow_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		who: T::AccountId,
+	) -> DispatchResult {
+		let details = Item::<T, I>::get(collection, item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(details.owner == who, Error::<T, I>::NoPermission);
+
+		Self::do_transfer(collection, item, Self::escrow_account(), |_, _| Ok(()))?;
+		ItemEscrowOf::<T, I>::insert(collection, item, &who);
+
+		Self::deposit_event(Event::ItemEscrowed { collection, item, depositor: who });
+		Ok(())
+	}
+
+	/// Releases an escrowed item to the specified account.
+	///
+	/// - `collection`: The ID of the collection to which the item belongs.
+	/// - `item`: The ID of the escrowed item.
+	/// - `to`: The account to which the item is released.
+	/// - `maybe_check_origin`: An optional account ID which must be either the depositor of the
+	///   escrow or an admin of the collection. If `None`, it's considered the root account.
+	///
+	/// Emits `EscrowReleased` event upon success.
+	/// Returns `Ok(())` on success, or one of the following dispatch errors:
+	/// - `NotEscrowed`: The item is not held in escrow.
+	/// - `NoPermission`: The caller is neither the depositor nor an admin of the collection.
+	/// - Any error returned by [`Self::do_transfer`].
+	pub fn release_escrow(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		to: T::AccountId,
+		maybe_check_origin: Option<T::AccountId>,
+	) -> DispatchResult {
+		let depositor =
+			ItemEscrowOf::<T, I>::get(collection, item).ok_or(Error::<T, I>::NotEscrowed)?;
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				check_origin == &depositor ||
+					Self::has_role(&collection, check_origin, CollectionRole::Admin),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		Self::do_transfer(collection, item, to.clone(), |_, _| Ok(()))?;
+		ItemEscrowOf::<T, I>::remove(collection, item);
+
+		Self::deposit_event(Event::EscrowReleased { collection, item, to });
+		Ok(())
+	}
+
+	/// Gifts an item to a recipient, who can claim it from the specified block.
+	///
+	/// - `collection`: The ID of the collection to which the item belongs.
+	/// - `item`: The ID of the item to be gifted.
+	/// - `who`: The owner of the item.
+	/// - `recipient`: The account which can claim the item.
+	/// - `claimable_at`: The block from which the item can be claimed.
+	/// - `expires_at`: The last block at which the item can be claimed, if any.
+	///
+	/// The item is held by [`Self::escrow_account`] until it's claimed, see
+	/// [`Self::claim_gift`], or until the gift is cancelled by `who`, see [`Self::cancel_gift`].
+	///
+	/// Emits `ItemGifted` event upon success.
+	/// Returns `Ok(())` on success, or one of the following dispatch errors:
+	/// - `UnknownItem`: The specified item does not exist.
+	/// - `NoPermission`: `who` is not the owner of the item.
+	/// - `InvalidGiftExpiry`: The gift expires before it can be claimed.
+	/// - Any error returned by [`Self::do_transfer`].
+	pub fn gift_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		who: T::AccountId,
+		recipient: T::AccountId,
+		claimable_at: BlockNumberFor<T>,
+		expires_at: Option<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let details = Item::<T, I>::get(collection, item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(details.owner == who, Error::<T, I>::NoPermission);
+		ensure!(
+			expires_at.is_none_or(|expires_at| claimable_at <= expires_at),
+			Error::<T, I>::InvalidGiftExpiry
+		);
+
+		Self::do_transfer(collection, item, Self::escrow_account(), |_, _| Ok(()))?;
+		PendingGiftOf::<T, I>::insert(
+			collection,
+			item,
+			PendingGift {
+				sender: who.clone(),
+				recipient: recipient.clone(),
+				claimable_at,
+				expires_at,
+			},
+		);
+
+		Self::deposit_event(Event::ItemGifted {
+			collection,
+			item,
+			from: who,
+			recipient,
+			claimable_at,
+			expires_at,
+		});
+		Ok(())
+	}
+
+	/// Claims a gifted item, transferring it to its recipient.
+	///
+	/// - `collection`: The ID of the collection to which the item belongs.
+	/// - `item`: The ID of the gifted item.
+	/// - `who`: The recipient of the gift.
+	///
+	/// Emits `GiftClaimed` event upon success.
+	/// Returns `Ok(())` on success, or one of the following dispatch errors:
+	/// - `UnknownGift`: The item has no pending gift.
+	/// - `NoPermission`: `who` is not the recipient of the gift.
+	/// - `GiftLocked`: The block from which the gift can be claimed hasn't been reached yet.
+	/// - `GiftExpired`: The last block at which the gift could be claimed has passed.
+	/// - Any error returned by [`Self::do_transfer`].
+	pub fn claim_gift(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		who: T::AccountId,
+	) -> DispatchResult {
+		let gift =
+			PendingGiftOf::<T, I>::get(collection, item).ok_or(Error::<T, I>::UnknownGift)?;
+		ensure!(gift.recipient == who, Error::<T, I>::NoPermission);
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now >= gift.claimable_at, Error::<T, I>::GiftLocked);
+		ensure!(
+			gift.expires_at.is_none_or(|expires_at| now <= expires_at),
+			Error::<T, I>::GiftExpired
+		);
+
+		Self::do_transfer(collection, item, gift.recipient.clone(), |_, _| Ok(()))?;
+		PendingGiftOf::<T, I>::remove(collection, item);
+
+		Self::deposit_event(Event::GiftClaimed { collection, item, recipient: gift.recipient });
+		Ok(())
+	}
+
+	/// Cancels a gift which hasn't been claimed yet, returning the item to its sender.
+	///
+	/// - `collection`: The ID of the collection to which the item belongs.
+	/// - `item`: The ID of the gifted item.
+	/// - `who`: The sender of the gift.
+	///
+	/// A gift can be cancelled at any time before it's claimed, including after it has expired.
+	///
+	/// Emits `GiftCancelled` event upon success.
+	/// Returns `Ok(())` on success, or one of the following dispatch errors:
+	/// - `UnknownGift`: The item has no pending gift.
+	/// - `NoPermission`: `who` is not the sender of the gift.
+	/// - Any error returned by [`Self::do_transfer`].
+	pub fn cancel_gift(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		who: T::AccountId,
+	) -> DispatchResult {
+		let gift =
+			PendingGiftOf::<T, I>::get(collection, item).ok_or(Error::<T, I>::UnknownGift)?;
+		ensure!(gift.sender == who, Error::<T, I>::NoPermission);
+
+		Self::do_transfer(collection, item, gift.sender.clone(), |_, _| Ok(()))?;
+		PendingGiftOf::<T, I>::remove(collection, item);
+
+		Self::deposit_event(Event::GiftCancelled { collection, item, sender: gift.sender });
+		Ok(())
+	}
+}
@@ -18,6 +18,8 @@
 //! This module contains helper methods to configure locks on collections and items for the NFTs
 //! pallet.
 
+use alloc::vec::Vec;
+
 use frame_support::pallet_prelude::*;
 
 use crate::*;
@@ -164,4 +166,49 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})
 	}
+
+	/// Returns every restriction which currently applies to an item.
+	///
+	/// - `collection`: The identifier of the collection to which the item belongs.
+	/// - `item`: The identifier of the item to be inspected.
+	///
+	/// Returns an empty list if the item is unrestricted or doesn't exist.
+	pub fn lock_reasons(collection: T::CollectionId, item: T::ItemId) -> Vec<LockReason> {
+		let mut reasons = Vec::new();
+		if !Item::<T, I>::contains_key(collection, item) {
+			return reasons
+		}
+
+		if T::Locker::is_locked(collection, item) {
+			reasons.push(LockReason::Locker);
+		}
+		if Self::has_system_attribute(&collection, &item, PalletAttributes::TransferDisabled)
+			.unwrap_or(false)
+		{
+			reasons.push(LockReason::TransferDisabled);
+		}
+		if let Ok(config) = Self::get_collection_config(&collection) {
+			if config.has_disabled_setting(CollectionSetting::TransferableItems) {
+				reasons.push(LockReason::CollectionNonTransferable);
+			}
+		}
+		if let Ok(config) = Self::get_item_config(&collection, &item) {
+			if config.has_disabled_setting(ItemSetting::Transferable) {
+				reasons.push(LockReason::ItemNonTransferable);
+			}
+			if config.has_disabled_setting(ItemSetting::UnlockedMetadata) {
+				reasons.push(LockReason::MetadataLocked);
+			}
+			if config.has_disabled_setting(ItemSetting::UnlockedAttributes) {
+				reasons.push(LockReason::AttributesLocked);
+			}
+		}
+		if ItemEscrowOf::<T, I>::contains_key(collection, item) {
+			reasons.push(LockReason::Escrowed);
+		}
+		if PendingGiftOf::<T, I>::contains_key(collection, item) {
+			reasons.push(LockReason::Gifted);
+		}
+		reasons
+	}
 }
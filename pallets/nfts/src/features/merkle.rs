@@ -0,0 +1,152 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains helper methods to compute and verify Merkle commitments over the items
+//! of a collection, e.g. for light-client verification or allowlist drops.
+
+use alloc::vec::Vec;
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::tokens::nonfungibles_v2::{Inspect, Mutate},
+};
+use sp_runtime::traits::Hash;
+
+use crate::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Computes the Merkle root over the items of a collection.
+	///
+	/// - `collection`: The collection whose items are committed to.
+	/// - `limit`: The maximum number of items which may be read.
+	///
+	/// The leaves are the hashes of the `(item, owner)` tuples ordered by item ID. All hashing is
+	/// done with the runtime's hasher, see [`Self::merkle_root`].
+	///
+	/// Returns `Ok(None)` if the collection has no items.
+	///
+	/// # Errors
+	///
+	/// This function returns a dispatch error in the following cases:
+	/// - If the collection ID is invalid ([`UnknownCollection`](crate::Error::UnknownCollection)).
+	/// - If the collection has more than `limit` items
+	///   ([`LimitExceeded`](crate::Error::LimitExceeded)).
+	pub fn items_merkle_root(
+		collection: T::CollectionId,
+		limit: u32,
+	) -> Result<Option<T::Hash>, DispatchError>
+	where
+		T::ItemId: Ord,
+	{
+		let details =
+			Collection::<T, I>::get(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		ensure!(details.items <= limit, Error::<T, I>::LimitExceeded);
+
+		let mut items: Vec<_> = Item::<T, I>::iter_prefix(collection)
+			.map(|(item, details)| (item, details.owner))
+			.collect();
+		items.sort_by_key(|(item, _)| *item);
+
+		let leaves = items.iter().map(T::Hashing::hash_of).collect();
+		Ok(Self::merkle_root(leaves))
+	}
+
+	/// Commits a set of items of a collection by its Merkle root, e.g. for allowlist drops.
+	///
+	/// - `collection`: The collection for which the set is committed.
+	/// - `root`: The Merkle root of the set, whose leaves are the hashes of the item IDs.
+	/// - `maybe_check_origin`: An optional account ID which must be an admin of the collection. If
+	///   `None`, it's considered the root account.
+	///
+	/// The root is stored as a collection attribute in the `Pallet` namespace and is used by
+	/// [`Self::verify_item_in_set`].
+	pub fn commit_item_set(
+		collection: T::CollectionId,
+		root: T::Hash,
+		maybe_check_origin: Option<T::AccountId>,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, check_origin, CollectionRole::Admin),
+				Error::<T, I>::NoPermission
+			);
+		}
+		<Self as Mutate<T::AccountId, ItemConfig>>::set_typed_collection_attribute(
+			&collection,
+			&PalletAttributes::<T::CollectionId>::ItemSetRoot,
+			&root,
+		)
+	}
+
+	/// Verifies that an item belongs to the committed set of items of a collection.
+	///
+	/// - `collection`: The collection for which the set was committed.
+	/// - `item`: The item whose membership is verified.
+	/// - `merkle_proof`: The sibling nodes on the path from the item's leaf to the root.
+	/// - `root`: The expected Merkle root, which must match the committed one.
+	///
+	/// Returns `true` if the proof is valid for the committed root, `false` otherwise.
+	pub fn verify_item_in_set(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		merkle_proof: Vec<T::Hash>,
+		root: T::Hash,
+	) -> bool {
+		let committed_root: Option<T::Hash> =
+			<Self as Inspect<T::AccountId>>::typed_system_attribute(
+				&collection,
+				None,
+				&PalletAttributes::<T::CollectionId>::ItemSetRoot,
+			);
+		if committed_root != Some(root) {
+			return false
+		}
+
+		let computed_root = merkle_proof
+			.iter()
+			.fold(T::Hashing::hash_of(&item), |node, sibling| Self::hash_nodes(&node, sibling));
+		computed_root == root
+	}
+
+	/// Computes the Merkle root of the provided leaves.
+	///
+	/// Each pair of nodes is hashed in sorted order, so that proofs don't need to specify the
+	/// position of the nodes. A node without a sibling is promoted to the next layer as is.
+	///
+	/// Returns `None` if no leaves are provided.
+	pub(crate) fn merkle_root(mut layer: Vec<T::Hash>) -> Option<T::Hash> {
+		while layer.len() > 1 {
+			layer = layer
+				.chunks(2)
+				.map(|nodes| match nodes {
+					[left, right] => Self::hash_nodes(left, right),
+					_ => nodes[0],
+				})
+				.collect();
+		}
+		layer.pop()
+	}
+
+	/// Hashes a pair of Merkle tree nodes in sorted order.
+	pub(crate) fn hash_nodes(a: &T::Hash, b: &T::Hash) -> T::Hash {
+		if a <= b {
+			T::Hashing::hash_of(&(a, b))
+		} else {
+			T::Hashing::hash_of(&(b, a))
+		}
+	}
+}
@@ -20,7 +20,10 @@
 use alloc::vec::Vec;
 use core::cmp::Ordering;
 
-use frame_support::pallet_prelude::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::tokens::nonfungibles_v2::{Inspect, Mutate},
+};
 
 use crate::*;
 
@@ -108,6 +111,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			});
 
 			Collection::<T, I>::insert(collection, &collection_details);
+			ItemMetadataVersion::<T, I>::mutate(collection, item, |version| {
+				version.saturating_inc()
+			});
 			Self::deposit_event(Event::ItemMetadataSet { collection, item, data });
 			Ok(())
 		})
@@ -161,6 +167,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 
 		Collection::<T, I>::insert(collection, &collection_details);
+		ItemMetadataVersion::<T, I>::mutate(collection, item, |version| version.saturating_inc());
 		Self::deposit_event(Event::ItemMetadataCleared { collection, item });
 
 		Ok(())
@@ -225,6 +232,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Collection::<T, I>::insert(collection, details);
 
 			*metadata = Some(CollectionMetadata { deposit, data: data.clone() });
+			CollectionMetadataVersion::<T, I>::mutate(collection, |version| {
+				version.saturating_inc()
+			});
 
 			Self::deposit_event(Event::CollectionMetadataSet { collection, data });
 			Ok(())
@@ -270,11 +280,90 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			T::Currency::unreserve(&details.owner, deposit);
 			details.owner_deposit.saturating_reduce(deposit);
 			Collection::<T, I>::insert(collection, details);
+			CollectionMetadataVersion::<T, I>::mutate(collection, |version| {
+				version.saturating_inc()
+			});
 			Self::deposit_event(Event::CollectionMetadataCleared { collection });
 			Ok(())
 		})
 	}
 
+	/// Returns the version of a collection's metadata, incremented each time it's set or cleared.
+	///
+	/// Clients caching the metadata can compare versions to cheaply detect changes.
+	pub fn metadata_version(collection: T::CollectionId) -> u32 {
+		CollectionMetadataVersion::<T, I>::get(collection)
+	}
+
+	/// Returns the version of an item's metadata, incremented each time it's set or cleared.
+	///
+	/// The version is removed together with the item when it's burned, so it starts from zero
+	/// again if an item with the same ID is minted later.
+	pub fn item_metadata_version(collection: T::CollectionId, item: T::ItemId) -> u32 {
+		ItemMetadataVersion::<T, I>::get(collection, item)
+	}
+
+	/// Sets the base URI for a specific collection.
+	///
+	/// - `collection`: The ID of the collection for which to set the base URI.
+	/// - `uri`: The base URI to which item IDs are appended when composing item URIs.
+	/// - `maybe_check_owner`: An optional account ID used to check ownership permission. If `None`,
+	///   it's considered the root account.
+	///
+	/// The base URI is stored as a collection attribute in the `Pallet` namespace and is used by
+	/// [`Self::token_uri`].
+	/// Returns `Ok(())` on success, or one of the following dispatch errors:
+	/// - `UnknownCollection`: The specified collection does not exist.
+	/// - `NoPermission`: The caller is not the owner of the collection.
+	pub fn set_base_uri(
+		collection: T::CollectionId,
+		uri: Vec<u8>,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let details =
+			Collection::<T, I>::get(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+		}
+
+		<Self as Mutate<T::AccountId, ItemConfig>>::set_collection_attribute(
+			&collection,
+			&PalletAttributes::<T::CollectionId>::BaseUri.encode(),
+			&uri,
+		)
+	}
+
+	/// Returns the URI of a specific item within a collection.
+	///
+	/// - `collection`: The ID of the collection to which the item belongs.
+	/// - `item`: The ID of the item for which to compose the URI.
+	///
+	/// When the collection has a base URI set, the URI is the base URI followed by the decimal
+	/// representation of the item ID. Otherwise, it falls back to the item's metadata.
+	/// Returns `None` if the item does not exist or has no URI.
+	pub fn token_uri(collection: T::CollectionId, item: T::ItemId) -> Option<Vec<u8>>
+	where
+		T::ItemId: Into<u128>,
+	{
+		if !Item::<T, I>::contains_key(collection, item) {
+			return None
+		}
+
+		match <Self as Inspect<T::AccountId>>::system_attribute(
+			&collection,
+			None,
+			&PalletAttributes::<T::CollectionId>::BaseUri.encode(),
+		) {
+			Some(base_uri) => {
+				let id: u128 = item.into();
+				let mut uri = base_uri;
+				uri.extend_from_slice(alloc::format!("{}", id).as_bytes());
+				Some(uri)
+			},
+			None => ItemMetadataOf::<T, I>::get(collection, item).map(|m| m.data.into()),
+		}
+	}
+
 	/// A helper method to construct metadata.
 	///
 	/// # Errors
@@ -286,4 +375,75 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	) -> Result<BoundedVec<u8, T::StringLimit>, DispatchError> {
 		Ok(BoundedVec::try_from(metadata).map_err(|_| Error::<T, I>::IncorrectMetadata)?)
 	}
+
+	/// Evolves an item by replacing its metadata and attributes, keeping its ID and owner.
+	///
+	/// - `collection`: The identifier of the collection to which the item belongs.
+	/// - `item`: The identifier of the item to evolve.
+	/// - `new_metadata`: The new metadata of the item.
+	/// - `new_attributes`: The new attributes of the item, set in the `CollectionOwner` namespace.
+	/// - `who`: The account evolving the item, which must be an admin of the collection.
+	///
+	/// All the item's attributes in the `CollectionOwner` namespace are cleared before the new
+	/// ones are set. Both the cleared and the new attributes are limited by
+	/// [`Config::MaxAttributesPerCall`]. The changes are applied in a storage layer, so on any
+	/// error the item is left untouched.
+	///
+	/// # Errors
+	///
+	/// This function returns a dispatch error in the following cases:
+	/// - If `who` isn't an admin of the collection ([`NoPermission`](crate::Error::NoPermission)).
+	/// - If the item ID is invalid ([`UnknownItem`](crate::Error::UnknownItem)).
+	/// - If there are too many attributes to clear or set
+	///   ([`MaxAttributesLimitReached`](crate::Error::MaxAttributesLimitReached)).
+	/// - Any error returned while clearing or setting the metadata or attributes, e.g. when they
+	///   are locked.
+	pub fn evolve_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		new_metadata: Vec<u8>,
+		new_attributes: Vec<(Vec<u8>, Vec<u8>)>,
+		who: T::AccountId,
+	) -> DispatchResult {
+		ensure!(
+			Self::has_role(&collection, &who, CollectionRole::Admin),
+			Error::<T, I>::NoPermission
+		);
+		ensure!(Item::<T, I>::contains_key(collection, item), Error::<T, I>::UnknownItem);
+
+		let max_attributes = T::MaxAttributesPerCall::get() as usize;
+		ensure!(new_attributes.len() <= max_attributes, Error::<T, I>::MaxAttributesLimitReached);
+		let namespace = AttributeNamespace::CollectionOwner;
+		let old_keys: Vec<_> =
+			Attribute::<T, I>::iter_key_prefix((&collection, Some(item), &namespace))
+				.take(max_attributes.saturating_add(1))
+				.collect();
+		ensure!(old_keys.len() <= max_attributes, Error::<T, I>::MaxAttributesLimitReached);
+
+		let new_metadata = Self::construct_metadata(new_metadata)?;
+		frame_support::storage::with_storage_layer(|| {
+			for key in old_keys {
+				Self::do_clear_attribute(
+					Some(who.clone()),
+					collection,
+					Some(item),
+					namespace.clone(),
+					key,
+				)?;
+			}
+			Self::do_set_item_metadata(Some(who.clone()), collection, item, new_metadata, None)?;
+			for (key, value) in new_attributes {
+				Self::do_set_attribute(
+					who.clone(),
+					collection,
+					Some(item),
+					namespace.clone(),
+					Self::construct_attribute_key(key)?,
+					Self::construct_attribute_value(value)?,
+					who.clone(),
+				)?;
+			}
+			Ok(())
+		})
+	}
 }
@@ -21,8 +21,11 @@ pub mod attributes;
 pub mod buy_sell;
 pub mod create_delete_collection;
 pub mod create_delete_item;
+pub mod escrow;
 pub mod lock;
+pub mod merkle;
 pub mod metadata;
 pub mod roles;
 pub mod settings;
 pub mod transfer;
+pub mod transition;
@@ -141,6 +141,115 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// Returns the accounts holding the admin, issuer and freezer roles of a collection.
+	///
+	/// - `collection_id`: A collection to read the roles of.
+	///
+	/// A role which isn't assigned to any account defaults to the collection's owner.
+	///
+	/// Returns `Some((admin, issuer, freezer))` if the collection exists, `None` otherwise.
+	pub fn collection_roles(
+		collection_id: &T::CollectionId,
+	) -> Option<(T::AccountId, T::AccountId, T::AccountId)> {
+		let owner = Collection::<T, I>::get(collection_id)?.owner;
+		let account_by_role =
+			|role| Self::find_account_by_role(collection_id, role).unwrap_or_else(|| owner.clone());
+		Some((
+			account_by_role(CollectionRole::Admin),
+			account_by_role(CollectionRole::Issuer),
+			account_by_role(CollectionRole::Freezer),
+		))
+	}
+
+	/// Grant a role within a collection to an account.
+	///
+	/// - `collection`: The ID of the collection to grant the role in.
+	/// - `who`: The account to grant the role to.
+	/// - `role`: The role to grant.
+	/// - `maybe_check_owner`: An optional account ID used to check ownership permission. If `None`,
+	///   it is considered as the root.
+	///
+	/// As with [`Self::do_set_team`], a role is held by a single account, so the role is revoked
+	/// from its previous holder. The owner can only reassign a role which is currently held: once
+	/// a role is cleared, only the root can grant it again.
+	///
+	/// Emits `TeamChanged` event upon success.
+	pub fn set_role(
+		collection: T::CollectionId,
+		who: T::AccountId,
+		role: CollectionRole,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let is_root = maybe_check_owner.is_none();
+		Self::ensure_collection_owner(collection, maybe_check_owner)?;
+		// only root can change the role from `None` to `Some(account)`
+		let maybe_holder = Self::find_account_by_role(&collection, role);
+		ensure!(is_root || maybe_holder.is_some(), Error::<T, I>::NoPermission);
+		if let Some(holder) = maybe_holder {
+			Self::remove_account_role(&collection, &holder, role);
+		}
+		CollectionRoleOf::<T, I>::mutate(collection, &who, |maybe_roles| {
+			maybe_roles.get_or_insert_with(CollectionRoles::none).add_role(role)
+		});
+		Self::deposit_team_changed(collection);
+		Ok(())
+	}
+
+	/// Revoke a role within a collection from an account.
+	///
+	/// - `collection`: The ID of the collection to revoke the role in.
+	/// - `who`: The account to revoke the role from.
+	/// - `role`: The role to revoke.
+	/// - `maybe_check_owner`: An optional account ID used to check ownership permission. If `None`,
+	///   it is considered as the root.
+	///
+	/// Emits `TeamChanged` event upon success.
+	pub fn clear_role(
+		collection: T::CollectionId,
+		who: T::AccountId,
+		role: CollectionRole,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		Self::ensure_collection_owner(collection, maybe_check_owner)?;
+		Self::remove_account_role(&collection, &who, role);
+		Self::deposit_team_changed(collection);
+		Ok(())
+	}
+
+	/// Ensures `maybe_check_owner`, if provided, owns `collection`.
+	fn ensure_collection_owner(
+		collection: T::CollectionId,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let owner = Self::collection_owner(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = maybe_check_owner {
+			ensure!(check_owner == owner, Error::<T, I>::NoPermission);
+		}
+		Ok(())
+	}
+
+	/// Removes a role from an account, deleting its record once it has no roles left.
+	fn remove_account_role(collection: &T::CollectionId, who: &T::AccountId, role: CollectionRole) {
+		CollectionRoleOf::<T, I>::mutate_exists(collection, who, |maybe_roles| {
+			if let Some(roles) = maybe_roles {
+				roles.remove_role(role);
+				if roles.0.is_empty() {
+					*maybe_roles = None;
+				}
+			}
+		});
+	}
+
+	/// Emits a `TeamChanged` event with the current team of a collection.
+	fn deposit_team_changed(collection: T::CollectionId) {
+		Self::deposit_event(Event::TeamChanged {
+			collection,
+			issuer: Self::find_account_by_role(&collection, CollectionRole::Issuer),
+			admin: Self::find_account_by_role(&collection, CollectionRole::Admin),
+			freezer: Self::find_account_by_role(&collection, CollectionRole::Freezer),
+		});
+	}
+
 	/// Groups provided roles by account, given one account could have multiple roles.
 	///
 	/// - `input`: A vector of (Account, Role) tuples.
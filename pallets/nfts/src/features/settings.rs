@@ -82,6 +82,70 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// Set or unset the maximum number of items of a collection which any single account may hold.
+	///
+	/// - `maybe_check_owner`: An optional account ID used to check permissions. If `None`, it's
+	///   considered the root account.
+	/// - `collection`: The ID of the collection for which to set the holding limit.
+	/// - `max_per_account`: The new holding limit, or `None` to remove it.
+	///
+	/// The limit is enforced when items are minted or transferred, and doesn't apply to items
+	/// already held. Items held by [`Self::escrow_account`] don't count towards it.
+	///
+	/// Emits `CollectionMaxPerAccountSet` event upon success.
+	pub fn set_max_per_account(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		max_per_account: Option<u32>,
+	) -> DispatchResult {
+		let details =
+			Collection::<T, I>::get(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+		}
+
+		MaxPerAccountOf::<T, I>::set(collection, max_per_account);
+		Self::deposit_event(Event::CollectionMaxPerAccountSet { collection, max_per_account });
+		Ok(())
+	}
+
+	/// Returns the maximum number of items of a collection which any single account may hold, if
+	/// set.
+	pub fn max_per_account(collection: T::CollectionId) -> Option<u32> {
+		MaxPerAccountOf::<T, I>::get(collection)
+	}
+
+	/// Returns who can currently mint items in a collection through the `mint` call.
+	///
+	/// - `collection`: The ID of the collection.
+	///
+	/// Minting is [`MintPermission::Closed`] if the collection doesn't exist, its max supply is
+	/// reached, or the current block is outside of its mint window. Otherwise the permission
+	/// follows the collection's mint type.
+	pub fn mint_permission(collection: T::CollectionId) -> MintPermission<T::CollectionId> {
+		let maybe_details = Collection::<T, I>::get(collection);
+		let maybe_config = CollectionConfigOf::<T, I>::get(collection);
+		let (details, config) = match (maybe_details, maybe_config) {
+			(Some(details), Some(config)) => (details, config),
+			_ => return MintPermission::Closed,
+		};
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let mint_settings = config.mint_settings;
+		let supply_reached = config.max_supply.is_some_and(|max| details.items >= max);
+		let not_started = mint_settings.start_block.is_some_and(|start| now < start);
+		let ended = mint_settings.end_block.is_some_and(|end| now > end);
+		if supply_reached || not_started || ended {
+			return MintPermission::Closed
+		}
+
+		match mint_settings.mint_type {
+			MintType::Issuer => MintPermission::Issuer,
+			MintType::Public => MintPermission::Public,
+			MintType::HolderOf(collection_id) => MintPermission::HolderOf(collection_id),
+		}
+	}
+
 	/// Update the mint settings for a collection.
 	///
 	/// - `maybe_check_origin`: An optional account ID used to check issuer permissions.
@@ -118,6 +182,48 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// Set the window of blocks during which the items of a collection can be minted.
+	///
+	/// - `collection`: The ID of the collection for which to set the mint window.
+	/// - `start`: The first block at which items can be minted.
+	/// - `end`: The last block at which items can be minted.
+	/// - `maybe_check_owner`: An optional account ID used to check permissions. If `None`, it's
+	///   considered the root account.
+	///
+	/// The window is stored in the collection's mint settings and is enforced whenever an item
+	/// is minted. The `mint` call fails with `Error::MintNotStarted` before `start` and with
+	/// `Error::MintEnded` after `end`, while any other way of minting fails with
+	/// `Error::MintWindowClosed`.
+	///
+	/// Emits `CollectionMintSettingsUpdated` event upon success.
+	pub fn set_mint_window(
+		collection: T::CollectionId,
+		start: frame_system::pallet_prelude::BlockNumberFor<T>,
+		end: frame_system::pallet_prelude::BlockNumberFor<T>,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let details =
+			Collection::<T, I>::get(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+		}
+		ensure!(start <= end, Error::<T, I>::InvalidMintWindow);
+
+		CollectionConfigOf::<T, I>::try_mutate(collection, |maybe_config| {
+			let config = maybe_config.as_mut().ok_or(Error::<T, I>::NoConfig)?;
+			config.mint_settings.start_block = Some(start);
+			config.mint_settings.end_block = Some(end);
+			Self::deposit_event(Event::CollectionMintSettingsUpdated { collection });
+			Ok(())
+		})
+	}
+
+	/// Returns the `(start, end)` blocks of a collection's mint window, if the collection exists.
+	pub fn mint_window(collection: T::CollectionId) -> Option<MintWindowOf<T>> {
+		CollectionConfigOf::<T, I>::get(collection)
+			.map(|config| (config.mint_settings.start_block, config.mint_settings.end_block))
+	}
+
 	/// Get the configuration for a specific collection.
 	///
 	/// - `collection_id`: The ID of the collection for which to retrieve the configuration.
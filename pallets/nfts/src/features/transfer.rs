@@ -44,6 +44,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	///   ([`ItemLocked`](crate::Error::ItemLocked)).
 	/// - If the collection or item is non-transferable
 	///   ([`ItemsNonTransferable`](crate::Error::ItemsNonTransferable)).
+	/// - If the holding limit (if configured) for `dest` is reached
+	///   ([`HoldingLimitExceeded`](crate::Error::HoldingLimitExceeded)).
 	pub fn do_transfer(
 		collection: T::CollectionId,
 		item: T::ItemId,
@@ -83,12 +85,23 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		// Retrieve the item details.
 		let mut details = Item::<T, I>::get(collection, item).ok_or(Error::<T, I>::UnknownItem)?;
 
+		// Ensure the destination account doesn't exceed the holding limit.
+		let owner_changed = details.owner != dest;
+		if owner_changed {
+			Self::ensure_within_holding_limit(&collection, &dest)?;
+		}
+
 		// Perform the transfer with custom details using the provided closure.
 		with_details(&collection_details, &mut details)?;
 
 		// Update account ownership information.
 		Account::<T, I>::remove((&details.owner, &collection, &item));
 		Account::<T, I>::insert((&dest, &collection, &item), ());
+		if owner_changed {
+			Self::decrement_account_balance(&collection, &details.owner);
+			Self::increment_account_balance(&collection, &dest);
+			ItemTransferCount::<T, I>::mutate(collection, item, |count| count.saturating_inc());
+		}
 		let origin = details.owner;
 		details.owner = dest;
 
@@ -152,6 +165,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			CollectionAccount::<T, I>::insert(&new_owner, collection, ());
 
 			details.owner = new_owner.clone();
+			PendingCollectionOwner::<T, I>::remove(collection);
 			OwnershipAcceptance::<T, I>::remove(&new_owner);
 			frame_system::Pallet::<T>::dec_consumers(&new_owner);
 
@@ -226,10 +240,74 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			CollectionAccount::<T, I>::remove(&details.owner, collection);
 			CollectionAccount::<T, I>::insert(&owner, collection, ());
 			details.owner = owner.clone();
+			PendingCollectionOwner::<T, I>::remove(collection);
 
 			// Emit `OwnerChanged` event.
 			Self::deposit_event(Event::OwnerChanged { collection, new_owner: owner });
 			Ok(())
 		})
 	}
+
+	/// Propose an account to become the owner of a collection.
+	///
+	/// - `collection`: The ID of the collection to transfer ownership.
+	/// - `new_owner`: The account proposed to become the owner of the collection.
+	/// - `maybe_check_owner`: An optional account ID used to check permissions. If `None`, it's
+	///   considered the root account.
+	///
+	/// Ownership only changes once the proposed account accepts it, see
+	/// [`Self::accept_collection_ownership`]. A new proposal replaces any pending one.
+	///
+	/// This is the counterpart of [`Self::do_set_accept_ownership`] followed by
+	/// [`Self::do_transfer_ownership`]: the owner proposes first and the transfer completes when
+	/// the proposed account accepts, so neither party has to wait for the other to act.
+	///
+	/// Emits `CollectionOwnerProposed` event upon success.
+	pub fn propose_collection_owner(
+		collection: T::CollectionId,
+		new_owner: T::AccountId,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let details =
+			Collection::<T, I>::get(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+		}
+
+		PendingCollectionOwner::<T, I>::insert(collection, &new_owner);
+		Self::deposit_event(Event::CollectionOwnerProposed {
+			collection,
+			proposed_owner: new_owner,
+		});
+		Ok(())
+	}
+
+	/// Accept the ownership of a collection proposed by its owner.
+	///
+	/// - `collection`: The ID of the collection to accept ownership of.
+	/// - `who`: The account accepting ownership, which must be the proposed owner.
+	///
+	/// The acceptance is recorded through [`Self::do_set_accept_ownership`], replacing any other
+	/// collection `who` accepted, and the collection is then transferred by its owner through
+	/// [`Self::do_transfer_ownership`], which moves the collection's deposit to `who`.
+	///
+	/// Emits `OwnerChanged` event upon success.
+	pub fn accept_collection_ownership(
+		collection: T::CollectionId,
+		who: T::AccountId,
+	) -> DispatchResult {
+		let proposed_owner =
+			PendingCollectionOwner::<T, I>::get(collection).ok_or(Error::<T, I>::Unaccepted)?;
+		ensure!(proposed_owner == who, Error::<T, I>::NoPermission);
+		let owner = Self::collection_owner(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if owner == who {
+			PendingCollectionOwner::<T, I>::remove(collection);
+			return Ok(())
+		}
+
+		frame_support::storage::with_storage_layer(|| {
+			Self::do_set_accept_ownership(who.clone(), Some(collection))?;
+			Self::do_transfer_ownership(owner, collection, who)
+		})
+	}
 }
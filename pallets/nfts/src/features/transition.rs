@@ -0,0 +1,96 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains helper methods to apply a batch of item operations atomically, e.g. a
+//! turn of a game.
+
+use alloc::vec::Vec;
+
+use frame_support::pallet_prelude::*;
+
+use crate::*;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Applies a sequence of item operations as a single state transition.
+	///
+	/// - `who`: The account applying the transition.
+	/// - `ops`: The operations to apply, in order.
+	///
+	/// Each operation is checked against `who`:
+	/// - `Mint`: `who` must be an issuer of the collection and pays the item deposit.
+	/// - `Transfer` and `Burn`: `who` must be the owner of the item.
+	/// - `SetAttribute`: `who` must be allowed to set attributes in the given namespace and pays
+	///   the attribute deposit.
+	///
+	/// The operations are applied in a storage layer, so either all of them are applied or, on
+	/// any error, none of them are.
+	///
+	/// # Errors
+	///
+	/// This function returns a dispatch error in the following cases:
+	/// - If there are more than [`Config::MaxTransitionOps`] operations
+	///   ([`LimitExceeded`](crate::Error::LimitExceeded)).
+	/// - If `who` isn't allowed to apply an operation
+	///   ([`NoPermission`](crate::Error::NoPermission)).
+	/// - Any error returned while applying an operation.
+	pub fn apply_transition(who: T::AccountId, ops: Vec<ItemOpOf<T, I>>) -> DispatchResult {
+		ensure!(ops.len() <= T::MaxTransitionOps::get() as usize, Error::<T, I>::LimitExceeded);
+
+		frame_support::storage::with_storage_layer(|| {
+			for op in ops {
+				Self::apply_op(&who, op)?;
+			}
+			Ok(())
+		})
+	}
+
+	/// Applies a single operation of a state transition on behalf of `who`.
+	fn apply_op(who: &T::AccountId, op: ItemOpOf<T, I>) -> DispatchResult {
+		match op {
+			ItemOp::Mint { collection, item, mint_to } => {
+				ensure!(
+					Self::has_role(&collection, who, CollectionRole::Issuer),
+					Error::<T, I>::NoPermission
+				);
+				let item_config =
+					ItemConfig { settings: Self::get_default_item_settings(&collection)? };
+				Self::do_mint(collection, item, Some(who.clone()), mint_to, item_config, |_, _| {
+					Ok(())
+				})
+			},
+			ItemOp::Transfer { collection, item, dest } =>
+				Self::do_transfer(collection, item, dest, |_, details| {
+					ensure!(&details.owner == who, Error::<T, I>::NoPermission);
+					Ok(())
+				}),
+			ItemOp::SetAttribute { collection, item, namespace, key, value } =>
+				Self::do_set_attribute(
+					who.clone(),
+					collection,
+					Some(item),
+					namespace,
+					Self::construct_attribute_key(key)?,
+					Self::construct_attribute_value(value)?,
+					who.clone(),
+				),
+			ItemOp::Burn { collection, item } => Self::do_burn(collection, item, |details| {
+				ensure!(&details.owner == who, Error::<T, I>::NoPermission);
+				Ok(())
+			}),
+		}
+	}
+}
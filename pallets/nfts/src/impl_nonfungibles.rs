@@ -27,6 +27,9 @@ use sp_runtime::{DispatchError, DispatchResult};
 
 use super::*;
 
+/// The maximum number of attributes cleared by [`Pallet::clear_all_attributes`].
+const MAX_ATTRIBUTES_CLEARED: usize = 100;
+
 impl<T: Config<I>, I: 'static> Inspect<<T as SystemConfig>::AccountId> for Pallet<T, I> {
 	type CollectionId = T::CollectionId;
 	type ItemId = T::ItemId;
@@ -405,6 +408,219 @@ impl<T: Config<I>, I: 'static> Mutate<<T as SystemConfig>::AccountId, ItemConfig
 	}
 }
 
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Mint multiple items in a single operation, as with [`Mutate::mint_into`].
+	///
+	/// The items are minted in a storage layer, so if any of them fails (e.g. the item already
+	/// exists or the collection's `max_supply` is reached) none of them are minted.
+	///
+	/// Returns the identifiers of the minted items.
+	pub fn mint_many_into(
+		collection: &T::CollectionId,
+		items: &[(T::ItemId, T::AccountId)],
+		item_config: &ItemConfig,
+		deposit_collection_owner: bool,
+	) -> Result<Vec<T::ItemId>, DispatchError> {
+		frame_support::storage::with_storage_layer(|| {
+			items
+				.iter()
+				.map(|(item, who)| {
+					<Self as Mutate<T::AccountId, ItemConfig>>::mint_into(
+						collection,
+						item,
+						who,
+						item_config,
+						deposit_collection_owner,
+					)
+					.map(|()| *item)
+				})
+				.collect()
+		})
+	}
+
+	/// Mint an item which can't be transferred, as with [`Mutate::mint_into`].
+	///
+	/// The [`TransferDisabled`](PalletAttributes::TransferDisabled) system attribute is set in
+	/// the same storage layer as the mint, so the item is never transferable. It can be lifted
+	/// with [`Transfer::enable_transfer`].
+	pub fn mint_soulbound(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		who: &T::AccountId,
+		item_config: &ItemConfig,
+		deposit_collection_owner: bool,
+	) -> DispatchResult {
+		frame_support::storage::with_storage_layer(|| {
+			<Self as Mutate<T::AccountId, ItemConfig>>::mint_into(
+				collection,
+				item,
+				who,
+				item_config,
+				deposit_collection_owner,
+			)?;
+			<Self as Transfer<T::AccountId>>::disable_transfer(collection, item)
+		})
+	}
+
+	/// Transfer multiple items of a collection to `destination`, as with [`Transfer::transfer`].
+	///
+	/// Every item is checked with [`Inspect::can_transfer`] before any of them is transferred,
+	/// so a single non-transferable item aborts the whole batch with
+	/// [`ItemLocked`](crate::Error::ItemLocked).
+	pub fn transfer_many(
+		collection: &T::CollectionId,
+		items: &[T::ItemId],
+		destination: &T::AccountId,
+	) -> DispatchResult {
+		for item in items {
+			ensure!(
+				<Self as Inspect<T::AccountId>>::can_transfer(collection, item),
+				Error::<T, I>::ItemLocked
+			);
+		}
+		frame_support::storage::with_storage_layer(|| {
+			for item in items {
+				Self::do_transfer(*collection, *item, destination.clone(), |_, _| Ok(()))?;
+			}
+			Ok(())
+		})
+	}
+
+	/// Clear all attributes of `item` of `collection` in `namespace`, as with
+	/// [`Mutate::clear_attribute`].
+	///
+	/// The deposits of the attributes are returned to the accounts that paid them.
+	///
+	/// # Errors
+	///
+	/// This function returns a [`TooManyAttributes`](crate::Error::TooManyAttributes) error if
+	/// the item has more than 100 attributes in `namespace`.
+	pub fn clear_all_attributes(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		namespace: &AttributeNamespace<T::AccountId>,
+	) -> DispatchResult {
+		let keys: Vec<_> = Attribute::<T, I>::iter_key_prefix((collection, Some(*item), namespace))
+			.take(MAX_ATTRIBUTES_CLEARED + 1)
+			.collect();
+		ensure!(keys.len() <= MAX_ATTRIBUTES_CLEARED, Error::<T, I>::TooManyAttributes);
+		frame_support::storage::with_storage_layer(|| {
+			for key in keys {
+				Self::do_clear_attribute(None, *collection, Some(*item), namespace.clone(), key)?;
+			}
+			Ok(())
+		})
+	}
+
+	/// Returns whether transferring `item` of `collection` is disabled by the
+	/// [`TransferDisabled`](PalletAttributes::TransferDisabled) system attribute, as set by
+	/// [`Transfer::disable_transfer`].
+	///
+	/// Unlike [`Inspect::can_transfer`], this doesn't account for the collection or item
+	/// settings.
+	///
+	/// # Errors
+	///
+	/// This function returns an [`UnknownItem`](crate::Error::UnknownItem) error if the item
+	/// doesn't exist.
+	pub fn is_transfer_disabled(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> Result<bool, DispatchError> {
+		ensure!(Item::<T, I>::contains_key(collection, item), Error::<T, I>::UnknownItem);
+		Self::has_system_attribute(collection, item, PalletAttributes::TransferDisabled)
+	}
+
+	/// Returns the number of items in `collection`, or `None` if the collection doesn't exist.
+	///
+	/// Unlike [`InspectEnumerable::items`], this reads the count cached in the collection
+	/// details instead of iterating the items.
+	pub fn item_count(collection: &T::CollectionId) -> Option<u32> {
+		Collection::<T, I>::get(collection).map(|details| details.items)
+	}
+
+	/// Returns the maximum number of items `collection` may hold, or `None` if it is unlimited or
+	/// the collection doesn't exist.
+	pub fn max_supply(collection: &T::CollectionId) -> Option<u32> {
+		CollectionConfigOf::<T, I>::get(collection).and_then(|config| config.max_supply)
+	}
+
+	/// Returns the number of items currently in `collection`, or `None` if the collection doesn't
+	/// exist.
+	///
+	/// This is the same as [`Self::item_count`], pairing with [`Self::max_supply`].
+	pub fn current_supply(collection: &T::CollectionId) -> Option<u32> {
+		Self::item_count(collection)
+	}
+
+	/// Returns the royalty recipient and basis points of `item` of `collection`.
+	///
+	/// The royalty set on the item takes precedence, falling back to the royalty of the
+	/// collection, see [`Self::set_royalty`].
+	pub fn royalty_of(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> Option<(T::AccountId, u16)> {
+		<Self as Inspect<T::AccountId>>::system_attribute(
+			collection,
+			Some(item),
+			&PalletAttributes::<T::CollectionId>::Royalty.encode(),
+		)
+		.and_then(|value| Decode::decode(&mut &value[..]).ok())
+		.or_else(|| Self::collection_royalty(*collection))
+	}
+
+	/// Sets the royalty paid to `recipient` on the sales of `item` of `collection`, or on the
+	/// sales of any item of `collection` without its own royalty if `item` is `None`.
+	///
+	/// # Errors
+	///
+	/// This function returns an [`InvalidBasisPoints`](crate::Error::InvalidBasisPoints) error if
+	/// `basis_points` exceeds 10_000.
+	pub fn set_royalty(
+		collection: &T::CollectionId,
+		item: Option<&T::ItemId>,
+		recipient: &T::AccountId,
+		basis_points: u16,
+	) -> DispatchResult {
+		ensure!(basis_points <= 10_000, Error::<T, I>::InvalidBasisPoints);
+		match item {
+			Some(item) => <Self as Mutate<T::AccountId, ItemConfig>>::set_typed_attribute(
+				collection,
+				item,
+				&PalletAttributes::<T::CollectionId>::Royalty,
+				&(recipient, basis_points),
+			),
+			None =>
+				Self::set_collection_royalty(*collection, recipient.clone(), basis_points, None),
+		}
+	}
+
+	/// Returns the number of items in `collection` held by `who`.
+	///
+	/// Unlike [`InspectEnumerable::owned_in_collection`], this reads the count tracked in
+	/// [`AccountBalance`] instead of iterating the items.
+	pub fn owned_count(collection: &T::CollectionId, who: &T::AccountId) -> u32 {
+		AccountBalance::<T, I>::get(collection, who)
+	}
+
+	/// Returns an iterator of the `(key, value)` attributes of `item` of `collection` in the
+	/// `CollectionOwner` namespace.
+	///
+	/// NOTE: iterating this list invokes a storage read per attribute.
+	pub fn attributes(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> impl Iterator<Item = (Vec<u8>, Vec<u8>)> {
+		Attribute::<T, I>::iter_prefix((
+			collection,
+			Some(*item),
+			AttributeNamespace::<T::AccountId>::CollectionOwner,
+		))
+		.map(|(key, (value, _))| (key.into(), value.into()))
+	}
+}
+
 impl<T: Config<I>, I: 'static> Transfer<T::AccountId> for Pallet<T, I> {
 	fn transfer(
 		collection: &Self::CollectionId,
@@ -56,9 +56,12 @@ use alloc::{boxed::Box, vec, vec::Vec};
 use core::cmp::Ordering;
 
 use codec::{Decode, Encode};
-use frame_support::traits::{
-	tokens::Locker, BalanceStatus::Reserved, Currency, EnsureOriginWithArg, Incrementable,
-	ReservableCurrency,
+use frame_support::{
+	traits::{
+		tokens::Locker, BalanceStatus::Reserved, Currency, EnsureOriginWithArg, Incrementable,
+		ReservableCurrency,
+	},
+	PalletId,
 };
 use frame_system::Config as SystemConfig;
 pub use pallet::*;
@@ -83,7 +86,7 @@ pub mod pallet {
 	use super::*;
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -224,6 +227,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxAttributesPerCall: Get<u32>;
 
+		/// The max number of operations applied in a single state transition.
+		#[pallet::constant]
+		type MaxTransitionOps: Get<u32>;
+
 		/// Disables some of pallet's features.
 		#[pallet::constant]
 		type Features: Get<PalletFeatures>;
@@ -238,6 +245,10 @@ pub mod pallet {
 		/// Must identify as an on-chain `Self::AccountId`.
 		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
 
+		/// The pallet's id, used for deriving the account which holds escrowed items.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		/// A set of helper functions for benchmarking.
 		type Helper: BenchmarkHelper<
@@ -280,6 +291,18 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The number of items of a collection held by any given account.
+	#[pallet::storage]
+	pub type AccountBalance<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
 	/// The collections owned by any given account; set out this way so that collections owned by
 	/// a single account can be enumerated.
 	#[pallet::storage]
@@ -354,6 +377,20 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The number of items of a collection having a given attribute value set in the
+	/// `CollectionOwner` namespace, used to compute the rarity of items.
+	#[pallet::storage]
+	pub type AttributeValueCount<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, BoundedVec<u8, T::KeyLimit>>,
+			NMapKey<Blake2_128Concat, BoundedVec<u8, T::ValueLimit>>,
+		),
+		u32,
+		ValueQuery,
+	>;
+
 	/// A price of an item.
 	#[pallet::storage]
 	pub type ItemPriceOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
@@ -418,6 +455,80 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The depositors of items held in escrow.
+	#[pallet::storage]
+	pub type ItemEscrowOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	/// The number of times any given item has changed owner since it was minted.
+	#[pallet::storage]
+	pub type ItemTransferCount<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// The block from which item transfers are counted in [`ItemTransferCount`], if they weren't
+	/// counted since genesis.
+	#[pallet::storage]
+	pub type TransferTrackingSince<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The number of times the metadata of any given collection has changed.
+	#[pallet::storage]
+	pub type CollectionMetadataVersion<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, u32, ValueQuery>;
+
+	/// The number of times the metadata of any given item has changed.
+	#[pallet::storage]
+	pub type ItemMetadataVersion<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// The accounts proposed to become the owners of collections, pending their acceptance.
+	#[pallet::storage]
+	pub type PendingCollectionOwner<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, T::AccountId, OptionQuery>;
+
+	/// The most recently minted item of any given collection.
+	#[pallet::storage]
+	pub type LastMinted<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, T::ItemId, OptionQuery>;
+
+	/// The maximum number of items of a collection which any single account may hold.
+	#[pallet::storage]
+	pub type MaxPerAccountOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, u32, OptionQuery>;
+
+	/// The gifted items held in escrow until they're claimed by their recipients.
+	#[pallet::storage]
+	pub type PendingGiftOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		PendingGiftFor<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	#[allow(missing_docs)]
@@ -599,6 +710,28 @@ pub mod pallet {
 			attribute: PalletAttributes<T::CollectionId>,
 			value: BoundedVec<u8, T::ValueLimit>,
 		},
+		/// An `item` was transferred into escrow by its `depositor`.
+		ItemEscrowed { collection: T::CollectionId, item: T::ItemId, depositor: T::AccountId },
+		/// An escrowed `item` was released to `to`.
+		EscrowReleased { collection: T::CollectionId, item: T::ItemId, to: T::AccountId },
+		/// An `item` was gifted to `recipient`, claimable from the `claimable_at` block until the
+		/// `expires_at` block, if any.
+		ItemGifted {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			from: T::AccountId,
+			recipient: T::AccountId,
+			claimable_at: BlockNumberFor<T>,
+			expires_at: Option<BlockNumberFor<T>>,
+		},
+		/// A gifted `item` was claimed by its `recipient`.
+		GiftClaimed { collection: T::CollectionId, item: T::ItemId, recipient: T::AccountId },
+		/// A gifted `item` was cancelled and returned to its `sender`.
+		GiftCancelled { collection: T::CollectionId, item: T::ItemId, sender: T::AccountId },
+		/// The maximum number of items any single account may hold in a `collection` was changed.
+		CollectionMaxPerAccountSet { collection: T::CollectionId, max_per_account: Option<u32> },
+		/// An account was proposed to become the owner of a `collection`.
+		CollectionOwnerProposed { collection: T::CollectionId, proposed_owner: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -693,6 +826,34 @@ pub mod pallet {
 		CollectionNotEmpty,
 		/// The witness data should be provided.
 		WitnessRequired,
+		/// The item is not held in escrow.
+		NotEscrowed,
+		/// The number of entries to process exceeds the provided limit.
+		LimitExceeded,
+		/// The given gift ID is unknown.
+		UnknownGift,
+		/// The gift can't be claimed yet.
+		GiftLocked,
+		/// The account holds the maximum number of items allowed in the collection.
+		HoldingLimitExceeded,
+		/// The attribute isn't declared in the collection's strict schema.
+		UndeclaredAttribute,
+		/// The basis points exceed 10_000, i.e. 100%.
+		InvalidBasisPoints,
+		/// There are too many attributes to process in a single call.
+		TooManyAttributes,
+		/// The provided limit must be greater than zero.
+		ZeroLimit,
+		/// The item can't be minted outside of the collection's mint window.
+		MintWindowClosed,
+		/// The mint window ends before it starts.
+		InvalidMintWindow,
+		/// The attribute value doesn't match the type declared in the collection's strict schema.
+		InvalidAttributeType,
+		/// The gift can no longer be claimed.
+		GiftExpired,
+		/// The gift expires before it can be claimed.
+		InvalidGiftExpiry,
 	}
 
 	#[pallet::call]
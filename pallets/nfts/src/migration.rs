@@ -66,7 +66,7 @@ pub mod v1 {
 				on_chain_version
 			);
 
-			if on_chain_version == 0 && in_code_version == 1 {
+			if on_chain_version == 0 {
 				let mut translated = 0u64;
 				let mut configs_iterated = 0u64;
 				Collection::<T>::translate::<
@@ -79,13 +79,12 @@ pub mod v1 {
 					Some(old_value.migrate_to_v1(item_configs))
 				});
 
-				in_code_version.put::<Pallet<T>>();
+				StorageVersion::new(1).put::<Pallet<T>>();
 
 				log::info!(
 					target: LOG_TARGET,
-					"Upgraded {} records, storage to version {:?}",
-					translated,
-					in_code_version
+					"Upgraded {} records, storage to version 1",
+					translated
 				);
 				T::DbWeight::get().reads_writes(translated + configs_iterated + 1, translated + 1)
 			} else {
@@ -120,3 +119,112 @@ pub mod v1 {
 		}
 	}
 }
+
+/// Migrations to version 2 of the pallet storage.
+pub mod v2 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// A migration which populates the storage items introduced in version 2 from the existing
+	/// pallet state:
+	/// - [`TransferTrackingSince`], set to the block in which the runtime is upgraded, as transfers
+	///   made before the upgrade weren't recorded in [`ItemTransferCount`].
+	/// - [`AccountBalance`], from the items held by each account in [`Account`].
+	/// - [`AttributeValueCount`], from the attributes of existing items in the `CollectionOwner`
+	///   namespace.
+	///
+	/// The migration runs in a single block, so at most `MaxEntries` entries of [`Account`] and
+	/// [`Attribute`] together are read. If the pallet holds more, nothing is migrated and the
+	/// storage version is left unchanged; `pre_upgrade` fails in that case, so that it's caught by
+	/// try-runtime before the upgrade is enacted.
+	pub struct MigrateToV2<T, MaxEntries, I = ()>(PhantomData<(T, MaxEntries, I)>);
+	impl<T: Config<I>, MaxEntries: Get<u32>, I: 'static> OnRuntimeUpgrade
+		for MigrateToV2<T, MaxEntries, I>
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T, I>::on_chain_storage_version();
+			if on_chain_version != 1 {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let max_entries = MaxEntries::get() as usize;
+			let accounts: Vec<_> = Account::<T, I>::iter_keys().take(max_entries + 1).collect();
+			let attributes: Vec<_> = Attribute::<T, I>::iter()
+				.take((max_entries + 1).saturating_sub(accounts.len()))
+				.collect();
+			let entries = (accounts.len() + attributes.len()) as u64;
+			if entries > max_entries as u64 {
+				log::error!(
+					target: LOG_TARGET,
+					"Migration did not execute, there are more than {} entries to migrate",
+					max_entries
+				);
+				return T::DbWeight::get().reads(entries + 1)
+			}
+
+			let mut reads = entries + 1;
+			let mut writes = 2;
+			for (who, collection, _) in accounts {
+				Pallet::<T, I>::increment_account_balance(&collection, &who);
+				reads.saturating_inc();
+				writes.saturating_inc();
+			}
+			for ((collection, maybe_item, namespace, key), (value, _)) in attributes {
+				let Some(item) = maybe_item else { continue };
+				if namespace != AttributeNamespace::CollectionOwner {
+					continue
+				}
+				reads.saturating_inc();
+				if Item::<T, I>::contains_key(collection, item) {
+					AttributeValueCount::<T, I>::mutate((&collection, &key, &value), |count| {
+						count.saturating_inc()
+					});
+					reads.saturating_inc();
+					writes.saturating_inc();
+				}
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			TransferTrackingSince::<T, I>::put(now);
+			StorageVersion::new(2).put::<Pallet<T, I>>();
+
+			log::info!(target: LOG_TARGET, "Migrated {} entries, storage to version 2", entries);
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let items = Account::<T, I>::iter_keys().count() as u32;
+			let entries = items.saturating_add(Attribute::<T, I>::iter_keys().count() as u32);
+			ensure!(
+				Pallet::<T, I>::on_chain_storage_version() != 1 || entries <= MaxEntries::get(),
+				"too many entries to migrate in a single block"
+			);
+			Ok(items.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_items: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_items: u32 = Decode::decode(&mut prev_items.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			ensure!(Pallet::<T, I>::on_chain_storage_version() >= 2, "wrong storage version");
+			ensure!(
+				TransferTrackingSince::<T, I>::exists(),
+				"the block from which transfers are tracked should be set"
+			);
+			let counted = AccountBalance::<T, I>::iter_values()
+				.fold(0u32, |counted, balance| counted.saturating_add(balance));
+			ensure!(
+				counted == prev_items,
+				"the account balances should add up to the number of held items"
+			);
+			Ok(())
+		}
+	}
+}
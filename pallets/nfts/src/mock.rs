@@ -60,6 +60,7 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
 	pub storage Features: PalletFeatures = PalletFeatures::all_enabled();
+	pub const NftsPalletId: PalletId = PalletId(*b"py/nfts_");
 }
 
 impl Config for Test {
@@ -82,12 +83,14 @@ impl Config for Test {
 	type MaxAttributesPerCall = ConstU32<2>;
 	type MaxDeadlineDuration = ConstU64<10000>;
 	type MaxTips = ConstU32<10>;
+	type MaxTransitionOps = ConstU32<4>;
 	type MetadataDepositBase = ConstU64<1>;
 	/// Using `AccountPublic` here makes it trivial to convert to `AccountId` via `into_account()`.
 	type OffchainPublic = AccountPublic;
 	/// Off-chain = signature On-chain - therefore no conversion needed.
 	/// It needs to be From<MultiSignature> for benchmarking.
 	type OffchainSignature = Signature;
+	type PalletId = NftsPalletId;
 	type RuntimeEvent = RuntimeEvent;
 	type StringLimit = ConstU32<50>;
 	type ValueLimit = ConstU32<50>;
@@ -21,18 +21,18 @@ use enumflags2::BitFlags;
 use frame_support::{
 	assert_noop, assert_ok,
 	traits::{
-		tokens::nonfungibles_v2::{Create, Destroy, Inspect, Mutate},
-		Currency, Get,
+		tokens::nonfungibles_v2::{Create, Destroy, Inspect, InspectRole, Mutate, Transfer},
+		ConstU32, Currency, Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion,
 	},
 };
 use pallet_balances::Error as BalancesError;
 use sp_core::{bounded::BoundedVec, Pair};
 use sp_runtime::{
-	traits::{Dispatchable, IdentifyAccount},
+	traits::{Dispatchable, Hash, IdentifyAccount},
 	MultiSignature, MultiSigner,
 };
 
-use crate::{mock::*, Event, SystemConfig, *};
+use crate::{features::attributes::MAX_COUNTED_ATTRIBUTES, mock::*, Event, SystemConfig, *};
 
 type AccountIdOf<Test> = <Test as frame_system::Config>::AccountId;
 
@@ -3876,3 +3876,1878 @@ fn clear_collection_metadata_works() {
 		assert_eq!(Balances::reserved_balance(&account(1)), 10);
 	});
 }
+
+#[test]
+fn collection_roles_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Nfts::collection_roles(&0), None);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_eq!(Nfts::collection_roles(&0), Some((account(1), account(1), account(1))));
+
+		assert_ok!(Nfts::set_team(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(account(2)),
+			Some(account(3)),
+			Some(account(4)),
+		));
+		assert_eq!(Nfts::collection_roles(&0), Some((account(3), account(2), account(4))));
+
+		// unassigned roles default to the collection owner
+		assert_ok!(Nfts::set_team(RuntimeOrigin::signed(account(1)), 0, None, None, None));
+		assert_eq!(Nfts::collection_roles(&0), Some((account(1), account(1), account(1))));
+	});
+}
+
+#[test]
+fn clear_attributes_with_prefix_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+
+		for (key, value) in [
+			(b"trait:color".to_vec(), b"red".to_vec()),
+			(b"trait:size".to_vec(), b"xl".to_vec()),
+			(b"name".to_vec(), b"x".to_vec()),
+		] {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				BoundedVec::truncate_from(key),
+				BoundedVec::truncate_from(value),
+			));
+		}
+		assert_eq!(attributes(0).len(), 3);
+		assert_eq!(Balances::reserved_balance(account(1)), 35);
+
+		// at most `limit` keys are read, so at most `limit` attributes are cleared
+		let cleared = Nfts::clear_attributes_with_prefix(
+			0,
+			0,
+			b"trait:",
+			AttributeNamespace::CollectionOwner,
+			1,
+			Some(account(1)),
+		)
+		.unwrap();
+		assert!(cleared <= 1);
+		assert_eq!(attributes(0).len() as u32, 3 - cleared);
+		assert_eq!(
+			Nfts::clear_attributes_with_prefix(
+				0,
+				0,
+				b"trait:",
+				AttributeNamespace::CollectionOwner,
+				10,
+				Some(account(1))
+			),
+			Ok(2 - cleared)
+		);
+		assert_eq!(
+			attributes(0),
+			vec![(Some(0), AttributeNamespace::CollectionOwner, b"name".to_vec(), b"x".to_vec())]
+		);
+		// the deposits of the cleared attributes are returned
+		assert_eq!(Balances::reserved_balance(account(1)), 7);
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 1);
+
+		// nothing left to clear under the prefix
+		assert_eq!(
+			Nfts::clear_attributes_with_prefix(
+				0,
+				0,
+				b"trait:",
+				AttributeNamespace::CollectionOwner,
+				10,
+				Some(account(1))
+			),
+			Ok(0)
+		);
+	});
+}
+
+#[test]
+fn clear_attributes_with_prefix_checks_origin_and_locks() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		for key in [b"trait:color".to_vec(), b"trait:size".to_vec()] {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				BoundedVec::truncate_from(key),
+				bvec![0],
+			));
+		}
+
+		// only an admin may clear the attributes in the `CollectionOwner` namespace
+		assert_noop!(
+			Nfts::clear_attributes_with_prefix(
+				0,
+				0,
+				b"trait:",
+				AttributeNamespace::CollectionOwner,
+				10,
+				Some(account(2))
+			),
+			Error::<Test>::NoPermission
+		);
+
+		// the attributes of a locked item can't be cleared
+		assert_ok!(Nfts::lock_item_properties(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			false,
+			true
+		));
+		assert_noop!(
+			Nfts::clear_attributes_with_prefix(
+				0,
+				0,
+				b"trait:",
+				AttributeNamespace::CollectionOwner,
+				10,
+				Some(account(1))
+			),
+			Error::<Test>::LockedItemAttributes
+		);
+		assert_eq!(attributes(0).len(), 2);
+	});
+}
+
+#[test]
+fn verify_attestation_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1_pair = sp_core::sr25519::Pair::from_string("//Alice", None).unwrap();
+		let user_1_signer = MultiSigner::Sr25519(user_1_pair.public());
+		let user_1 = user_1_signer.clone().into_account();
+		let attestation = b"appraised:1000".to_vec();
+		let message = Encode::encode(&(0u32, 1u32, attestation.clone()));
+		let signature = MultiSignature::Sr25519(user_1_pair.sign(&message));
+
+		assert!(Nfts::verify_attestation(0, 1, attestation.clone(), &signature, &user_1));
+
+		// the signature doesn't cover another item, another attestation or another signer
+		assert!(!Nfts::verify_attestation(0, 2, attestation.clone(), &signature, &user_1));
+		assert!(!Nfts::verify_attestation(0, 1, b"appraised:1".to_vec(), &signature, &user_1));
+		assert!(!Nfts::verify_attestation(0, 1, attestation, &signature, &account(1)));
+	});
+}
+
+#[test]
+fn base_uri_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 7, account(1), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![1, 2]));
+
+		// falls back to the item's metadata
+		assert_eq!(Nfts::token_uri(0, 7), None);
+		assert_eq!(Nfts::token_uri(0, 42), Some(vec![1, 2]));
+
+		assert_noop!(
+			Nfts::set_base_uri(0, b"ipfs://cid/".to_vec(), Some(account(2))),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::set_base_uri(1, b"ipfs://cid/".to_vec(), None),
+			Error::<Test>::UnknownCollection
+		);
+		assert_ok!(Nfts::set_base_uri(0, b"ipfs://cid/".to_vec(), Some(account(1))));
+		assert_eq!(
+			Nfts::system_attribute(&0, None, &PalletAttributes::<u32>::BaseUri.encode()),
+			Some(b"ipfs://cid/".to_vec())
+		);
+
+		assert_eq!(Nfts::token_uri(0, 7), Some(b"ipfs://cid/7".to_vec()));
+		assert_eq!(Nfts::token_uri(0, 42), Some(b"ipfs://cid/42".to_vec()));
+		assert_eq!(Nfts::token_uri(0, 8), None);
+	});
+}
+
+#[test]
+fn escrow_should_work() {
+	new_test_ext().execute_with(|| {
+		let escrow = Nfts::escrow_account();
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 43, account(2), None));
+
+		assert_noop!(Nfts::escrow_item(0, 42, account(3)), Error::<Test>::NoPermission);
+		assert_noop!(
+			Nfts::release_escrow(0, 42, account(2), Some(account(2))),
+			Error::<Test>::NotEscrowed
+		);
+
+		assert_ok!(Nfts::escrow_item(0, 42, account(2)));
+		assert_eq!(Item::<Test>::get(0, 42).unwrap().owner, escrow);
+		assert_eq!(ItemEscrowOf::<Test>::get(0, 42), Some(account(2)));
+		assert!(events().contains(&Event::<Test>::ItemEscrowed {
+			collection: 0,
+			item: 42,
+			depositor: account(2),
+		}));
+
+		// only the depositor or an admin can release the item
+		assert_noop!(
+			Nfts::release_escrow(0, 42, account(3), Some(account(3))),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::release_escrow(0, 42, account(2), Some(account(2))));
+		assert_eq!(Item::<Test>::get(0, 42).unwrap().owner, account(2));
+		assert_eq!(ItemEscrowOf::<Test>::get(0, 42), None);
+		assert!(events().contains(&Event::<Test>::EscrowReleased {
+			collection: 0,
+			item: 42,
+			to: account(2),
+		}));
+
+		assert_ok!(Nfts::escrow_item(0, 43, account(2)));
+		assert_ok!(Nfts::release_escrow(0, 43, account(3), Some(account(1))));
+		assert_eq!(Item::<Test>::get(0, 43).unwrap().owner, account(3));
+	});
+}
+
+#[test]
+fn export_collection_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_eq!(Nfts::export_collection(0, None, 3), Ok((vec![], None)));
+
+		for item in 0..5 {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				item,
+				account(item as u8 + 2),
+				None
+			));
+		}
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 3, bvec![3]));
+
+		// a zero limit makes no progress, so it's rejected rather than returning a `None` cursor
+		assert_noop!(Nfts::export_collection(0, None, 0), Error::<Test>::ZeroLimit);
+
+		let (mut exported, cursor) = Nfts::export_collection(0, None, 3).unwrap();
+		assert_eq!(exported.len(), 3);
+		assert!(cursor.is_some());
+		assert_noop!(Nfts::export_collection(0, cursor, 0), Error::<Test>::ZeroLimit);
+
+		let (second_page, cursor) = Nfts::export_collection(0, cursor, 3).unwrap();
+		assert_eq!(second_page.len(), 2);
+		assert_eq!(cursor, None);
+
+		exported.extend(second_page);
+		exported.sort_by_key(|(item, ..)| *item);
+		assert_eq!(
+			exported,
+			vec![
+				(0, account(2), None),
+				(1, account(3), None),
+				(2, account(4), None),
+				(3, account(5), Some(vec![3])),
+				(4, account(6), None),
+			]
+		);
+	});
+}
+
+#[test]
+fn items_merkle_root_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Nfts::items_merkle_root(0, 10), Error::<Test>::UnknownCollection);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_eq!(Nfts::items_merkle_root(0, 10), Ok(None));
+
+		for item in 0..3 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(1), None));
+		}
+		let root = Nfts::items_merkle_root(0, 10).unwrap();
+		assert!(root.is_some());
+		// the root is stable as long as the items don't change
+		assert_eq!(Nfts::items_merkle_root(0, 3), Ok(root));
+		assert_noop!(Nfts::items_merkle_root(0, 2), Error::<Test>::LimitExceeded);
+
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(1)), 0, 1, account(2)));
+		let new_root = Nfts::items_merkle_root(0, 10).unwrap();
+		assert_ne!(new_root, root);
+
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 1, account(1)));
+		assert_eq!(Nfts::items_merkle_root(0, 10), Ok(root));
+	});
+}
+
+#[test]
+fn expiring_approvals_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			account(2),
+			default_item_config()
+		));
+		assert_eq!(Nfts::expiring_approvals(0, 42, 100), vec![]);
+
+		assert_eq!(System::block_number(), 1);
+		for (delegate, maybe_deadline) in [(3, Some(2)), (4, Some(10)), (5, None)] {
+			assert_ok!(Nfts::approve_transfer(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				42,
+				account(delegate),
+				maybe_deadline
+			));
+		}
+
+		assert_eq!(Nfts::expiring_approvals(0, 42, 3), vec![]);
+		assert_eq!(Nfts::expiring_approvals(0, 42, 5), vec![(account(3), 3)]);
+		let mut expiring = Nfts::expiring_approvals(0, 42, 100);
+		expiring.sort();
+		assert_eq!(expiring, vec![(account(3), 3), (account(4), 11)]);
+		assert_eq!(Nfts::expiring_approvals(0, 43, 100), vec![]);
+	});
+}
+
+#[test]
+fn verify_item_in_set_should_work() {
+	new_test_ext().execute_with(|| {
+		type Hashing = <Test as frame_system::Config>::Hashing;
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+
+		let leaves: Vec<_> = [1u32, 2, 3].iter().map(Hashing::hash_of).collect();
+		let root = Nfts::merkle_root(leaves.clone()).unwrap();
+		let proof = vec![leaves[1], leaves[2]];
+		// nothing is committed yet
+		assert!(!Nfts::verify_item_in_set(0, 1, proof.clone(), root));
+
+		assert_noop!(Nfts::commit_item_set(0, root, Some(account(2))), Error::<Test>::NoPermission);
+		assert_ok!(Nfts::commit_item_set(0, root, Some(account(1))));
+
+		assert!(Nfts::verify_item_in_set(0, 1, proof.clone(), root));
+		let proof_of_third = vec![Nfts::hash_nodes(&leaves[0], &leaves[1])];
+		assert!(Nfts::verify_item_in_set(0, 3, proof_of_third, root));
+		// a forged proof or an item outside of the set is rejected
+		assert!(!Nfts::verify_item_in_set(0, 4, proof.clone(), root));
+		assert!(!Nfts::verify_item_in_set(0, 1, vec![leaves[2], leaves[1]], root));
+		// the root must match the committed one
+		let other_root = Nfts::merkle_root(vec![leaves[0], leaves[1]]).unwrap();
+		assert!(!Nfts::verify_item_in_set(0, 1, vec![leaves[1]], other_root));
+	});
+}
+
+#[test]
+fn gift_item_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+
+		assert_noop!(
+			Nfts::gift_item(0, 42, account(3), account(3), 5, None),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::gift_item(0, 42, account(2), account(3), 5, Some(4)),
+			Error::<Test>::InvalidGiftExpiry
+		);
+		assert_noop!(Nfts::claim_gift(0, 42, account(3)), Error::<Test>::UnknownGift);
+
+		assert_ok!(Nfts::gift_item(0, 42, account(2), account(3), 5, Some(6)));
+		assert_eq!(Item::<Test>::get(0, 42).unwrap().owner, Nfts::escrow_account());
+		assert_eq!(
+			PendingGiftOf::<Test>::get(0, 42),
+			Some(PendingGift {
+				sender: account(2),
+				recipient: account(3),
+				claimable_at: 5,
+				expires_at: Some(6),
+			})
+		);
+		assert!(events().contains(&Event::<Test>::ItemGifted {
+			collection: 0,
+			item: 42,
+			from: account(2),
+			recipient: account(3),
+			claimable_at: 5,
+			expires_at: Some(6),
+		}));
+
+		// the gift can only be claimed by its recipient once unlocked
+		assert_noop!(Nfts::claim_gift(0, 42, account(3)), Error::<Test>::GiftLocked);
+		System::set_block_number(5);
+		assert_noop!(Nfts::claim_gift(0, 42, account(2)), Error::<Test>::NoPermission);
+		assert_ok!(Nfts::claim_gift(0, 42, account(3)));
+		assert_eq!(Item::<Test>::get(0, 42).unwrap().owner, account(3));
+		assert_eq!(PendingGiftOf::<Test>::get(0, 42), None);
+		assert!(events().contains(&Event::<Test>::GiftClaimed {
+			collection: 0,
+			item: 42,
+			recipient: account(3),
+		}));
+
+		// an expired gift can't be claimed
+		assert_ok!(Nfts::gift_item(0, 42, account(3), account(4), 5, Some(6)));
+		System::set_block_number(7);
+		assert_noop!(Nfts::claim_gift(0, 42, account(4)), Error::<Test>::GiftExpired);
+	});
+}
+
+#[test]
+fn cancel_gift_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_noop!(Nfts::cancel_gift(0, 42, account(2)), Error::<Test>::UnknownGift);
+
+		assert_ok!(Nfts::gift_item(0, 42, account(2), account(3), 5, Some(6)));
+		// only the sender can cancel the gift
+		assert_noop!(Nfts::cancel_gift(0, 42, account(3)), Error::<Test>::NoPermission);
+
+		// the gift can be cancelled after it has expired
+		System::set_block_number(7);
+		assert_ok!(Nfts::cancel_gift(0, 42, account(2)));
+		assert_eq!(Item::<Test>::get(0, 42).unwrap().owner, account(2));
+		assert_eq!(PendingGiftOf::<Test>::get(0, 42), None);
+		assert!(events().contains(&Event::<Test>::GiftCancelled {
+			collection: 0,
+			item: 42,
+			sender: account(2),
+		}));
+		assert_noop!(Nfts::claim_gift(0, 42, account(3)), Error::<Test>::UnknownGift);
+	});
+}
+
+#[test]
+fn collection_total_deposit_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_noop!(Nfts::collection_total_deposit(0, 10), Error::<Test>::UnknownCollection);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_eq!(Nfts::collection_total_deposit(0, 10), Ok(0));
+
+		for item in 1..=3 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(2), None));
+		}
+		assert_eq!(Nfts::collection_total_deposit(0, 10), Ok(3));
+
+		// metadata deposit: `MetadataDepositBase` + `DepositPerByte` * 3
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 1, bvec![0u8; 3]));
+		assert_eq!(Nfts::collection_total_deposit(0, 10), Ok(7));
+
+		assert_noop!(Nfts::collection_total_deposit(0, 2), Error::<Test>::LimitExceeded);
+	});
+}
+
+#[test]
+fn holding_limit_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_noop!(
+			Nfts::set_max_per_account(Some(account(2)), 0, Some(2)),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::set_max_per_account(Some(account(1)), 0, Some(2)));
+		assert_eq!(Nfts::max_per_account(0), Some(2));
+		assert!(events().contains(&Event::<Test>::CollectionMaxPerAccountSet {
+			collection: 0,
+			max_per_account: Some(2),
+		}));
+
+		// minting up to the limit works
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 1, account(2), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 2, account(2), None));
+		assert_eq!(AccountBalance::<Test>::get(0, account(2)), 2);
+		assert_noop!(
+			Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 3, account(2), None),
+			Error::<Test>::HoldingLimitExceeded
+		);
+
+		// transfers are subject to the limit as well
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 3, account(3), None));
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 3, account(2)),
+			Error::<Test>::HoldingLimitExceeded
+		);
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 2, account(3)));
+		assert_eq!(AccountBalance::<Test>::get(0, account(2)), 1);
+		assert_eq!(AccountBalance::<Test>::get(0, account(3)), 2);
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 3, account(2)));
+
+		// burning frees up room
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(2)), 0, 1));
+		assert_eq!(AccountBalance::<Test>::get(0, account(2)), 1);
+		assert_ok!(Nfts::set_max_per_account(None, 0, None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 4, account(2), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 5, account(2), None));
+		assert_eq!(AccountBalance::<Test>::get(0, account(2)), 3);
+	});
+}
+
+#[test]
+fn is_pristine_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert!(!Nfts::is_pristine(0, 42));
+
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 43, account(2), None));
+		assert!(Nfts::is_pristine(0, 42));
+
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
+		assert!(!Nfts::is_pristine(0, 42));
+		assert_eq!(ItemTransferCount::<Test>::get(0, 42), 1);
+		assert!(Nfts::is_pristine(0, 43));
+
+		// the counter is reset once the item is burned
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(3)), 0, 42));
+		assert_eq!(ItemTransferCount::<Test>::get(0, 42), 0);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert!(Nfts::is_pristine(0, 42));
+	});
+}
+
+#[test]
+fn mint_window_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_eq!(Nfts::mint_window(0), Some((None, None)));
+		assert_eq!(Nfts::mint_window(1), None);
+
+		assert_noop!(Nfts::set_mint_window(0, 2, 3, Some(account(2))), Error::<Test>::NoPermission);
+		assert_noop!(
+			Nfts::set_mint_window(0, 3, 2, Some(account(1))),
+			Error::<Test>::InvalidMintWindow
+		);
+		assert_ok!(Nfts::set_mint_window(0, 2, 3, Some(account(1))));
+		assert_eq!(Nfts::mint_window(0), Some((Some(2), Some(3))));
+
+		assert_noop!(
+			Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None),
+			Error::<Test>::MintNotStarted
+		);
+		assert_noop!(
+			Nfts::force_mint(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				42,
+				account(1),
+				default_item_config()
+			),
+			Error::<Test>::MintWindowClosed
+		);
+		System::set_block_number(2);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
+		System::set_block_number(3);
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			43,
+			account(1),
+			default_item_config()
+		));
+		System::set_block_number(4);
+		assert_noop!(
+			Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 44, account(1), None),
+			Error::<Test>::MintEnded
+		);
+		assert_noop!(
+			Nfts::mint_into(&0, &44, &account(1), &default_item_config(), false),
+			Error::<Test>::MintWindowClosed
+		);
+	});
+}
+
+#[test]
+fn approve_many_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		for item in 1..=4 {
+			let owner = if item == 4 { account(3) } else { account(2) };
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, owner, None));
+		}
+
+		// reverts entirely if any item isn't owned by `owner`
+		assert_noop!(
+			Nfts::approve_many(0, &[1, 2, 4], account(2), account(5), None),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::approve_many(0, &[1, 2, 7], account(2), account(5), None),
+			Error::<Test>::UnknownItem
+		);
+
+		assert_ok!(Nfts::approve_many(0, &[1, 2, 3], account(2), account(5), Some(2)));
+		for item in 1..=3 {
+			assert!(Item::<Test>::get(0, item).unwrap().approvals.contains_key(&account(5)));
+		}
+		assert!(Item::<Test>::get(0, 4).unwrap().approvals.is_empty());
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(5)), 0, 3, account(5)));
+	});
+}
+
+#[test]
+fn last_minted_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_eq!(Nfts::last_minted(0), None);
+
+		for item in [3, 1, 2] {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(1), None));
+		}
+		assert_eq!(Nfts::last_minted(0), Some(2));
+
+		// burning another item keeps the pointer
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 3));
+		assert_eq!(Nfts::last_minted(0), Some(2));
+
+		// burning the last minted item clears it
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 2));
+		assert_eq!(Nfts::last_minted(0), None);
+
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 4, account(1), None));
+		assert_eq!(Nfts::last_minted(0), Some(4));
+	});
+}
+
+#[test]
+fn rarity_score_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		for (item, color) in [(1, b"red"), (2, b"red"), (3, b"red"), (4, b"gld")] {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(2), None));
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(item),
+				AttributeNamespace::CollectionOwner,
+				bvec![0],
+				color.to_vec().try_into().unwrap(),
+			));
+		}
+		let count = |value: &[u8]| {
+			AttributeValueCount::<Test>::get((
+				0,
+				BoundedVec::<u8, <Test as Config>::KeyLimit>::truncate_from(vec![0]),
+				BoundedVec::<u8, <Test as Config>::ValueLimit>::truncate_from(value.to_vec()),
+			))
+		};
+		assert_eq!(count(b"red"), 3);
+
+		// the item with the rarer trait scores higher
+		assert_eq!(Nfts::rarity_score(0, 1), Some(4 * 1_000 / 3));
+		assert_eq!(Nfts::rarity_score(0, 4), Some(4 * 1_000));
+		assert_eq!(Nfts::rarity_score(0, 5), None);
+
+		// counts follow updated and cleared values
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(3),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![b'g', b'l', b'd'],
+		));
+		assert_eq!(Nfts::rarity_score(0, 1), Some(4 * 1_000 / 2));
+		assert_eq!(Nfts::rarity_score(0, 4), Some(4 * 1_000 / 2));
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(1),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+		));
+		assert_eq!(count(b"red"), 1);
+		assert_eq!(Nfts::rarity_score(0, 1), Some(0));
+		assert_eq!(Nfts::rarity_score(0, 2), Some(4 * 1_000));
+
+		// burned items aren't counted until they are minted again
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(2)), 0, 4));
+		assert_eq!(count(b"gld"), 1);
+		assert_eq!(Nfts::rarity_score(0, 3), Some(3 * 1_000));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 4, account(2), None));
+		assert_eq!(count(b"gld"), 2);
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(2)), 0, 2));
+		assert_eq!(count(b"red"), 0);
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(2),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+		));
+		assert_eq!(count(b"red"), 0);
+		assert_eq!(count(b"gld"), 2);
+
+		// the number of attributes counted on mint and burn is bounded
+		for i in 0..MAX_COUNTED_ATTRIBUTES {
+			assert_ok!(Nfts::force_set_attribute(
+				RuntimeOrigin::root(),
+				None,
+				0,
+				Some(5),
+				AttributeNamespace::CollectionOwner,
+				BoundedVec::truncate_from(i.encode()),
+				bvec![0],
+			));
+		}
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 5, account(2), None));
+		let first_count = || {
+			AttributeValueCount::<Test>::get((
+				0,
+				BoundedVec::<u8, <Test as Config>::KeyLimit>::truncate_from(0u32.encode()),
+				BoundedVec::<u8, <Test as Config>::ValueLimit>::truncate_from(vec![0]),
+			))
+		};
+		assert_eq!(first_count(), 1);
+		assert_ok!(Nfts::force_set_attribute(
+			RuntimeOrigin::root(),
+			None,
+			0,
+			Some(5),
+			AttributeNamespace::CollectionOwner,
+			BoundedVec::truncate_from(MAX_COUNTED_ATTRIBUTES.encode()),
+			bvec![0],
+		));
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(account(2)), 0, 5),
+			Error::<Test>::TooManyAttributes
+		);
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(5),
+			AttributeNamespace::CollectionOwner,
+			BoundedVec::truncate_from(MAX_COUNTED_ATTRIBUTES.encode()),
+		));
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(2)), 0, 5));
+		assert_eq!(first_count(), 0);
+	});
+}
+
+#[test]
+fn two_step_collection_ownership_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		Balances::make_free_balance_be(&account(2), 100);
+		assert_noop!(Nfts::accept_collection_ownership(0, account(2)), Error::<Test>::Unaccepted);
+		assert_noop!(
+			Nfts::propose_collection_owner(0, account(2), Some(account(2))),
+			Error::<Test>::NoPermission
+		);
+
+		// an unaccepted proposal leaves the ownership unchanged
+		assert_ok!(Nfts::propose_collection_owner(0, account(2), Some(account(1))));
+		assert_eq!(PendingCollectionOwner::<Test>::get(0), Some(account(2)));
+		assert!(events().contains(&Event::<Test>::CollectionOwnerProposed {
+			collection: 0,
+			proposed_owner: account(2),
+		}));
+		assert_eq!(Nfts::collection_owner(0), Some(account(1)));
+		assert_noop!(Nfts::accept_collection_ownership(0, account(3)), Error::<Test>::NoPermission);
+
+		let consumers = System::consumers(&account(2));
+		assert_ok!(Nfts::accept_collection_ownership(0, account(2)));
+		assert_eq!(Nfts::collection_owner(0), Some(account(2)));
+		assert_eq!(PendingCollectionOwner::<Test>::get(0), None);
+		assert_eq!(collections(), vec![(account(2), 0)]);
+		// the acceptance is consumed by the transfer
+		assert_eq!(OwnershipAcceptance::<Test>::get(account(2)), None);
+		assert_eq!(System::consumers(&account(2)), consumers);
+		assert!(events()
+			.contains(&Event::<Test>::OwnerChanged { collection: 0, new_owner: account(2) }));
+	});
+}
+
+#[test]
+fn lock_reasons_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_eq!(Nfts::lock_reasons(0, 42), vec![]);
+		assert_eq!(Nfts::lock_reasons(0, 43), vec![]);
+
+		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert_ok!(Nfts::lock_item_properties(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			true,
+			false
+		));
+		assert_eq!(
+			Nfts::lock_reasons(0, 42),
+			vec![LockReason::ItemNonTransferable, LockReason::MetadataLocked]
+		);
+	});
+}
+
+#[test]
+fn owned_with_attribute_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		let items = [
+			(1, account(2), Some(1)),
+			(2, account(2), Some(2)),
+			(3, account(2), None),
+			(4, account(3), Some(1)),
+		];
+		for (item, owner, value) in items {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, owner, None));
+			if let Some(value) = value {
+				assert_ok!(Nfts::set_attribute(
+					RuntimeOrigin::signed(account(1)),
+					0,
+					Some(item),
+					AttributeNamespace::CollectionOwner,
+					bvec![0],
+					bvec![value],
+				));
+			}
+		}
+
+		assert_eq!(Nfts::owned_with_attribute(0, account(2), vec![0], vec![1], 10), vec![1]);
+		assert_eq!(Nfts::owned_with_attribute(0, account(3), vec![0], vec![1], 10), vec![4]);
+		assert_eq!(
+			Nfts::owned_with_attribute(0, account(2), vec![0], vec![3], 10),
+			Vec::<u32>::new()
+		);
+		assert_eq!(
+			Nfts::owned_with_attribute(0, account(2), vec![0], vec![1], 0),
+			Vec::<u32>::new()
+		);
+	});
+}
+
+#[test]
+fn evolve_item_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 5]));
+		for key in [1, 2] {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(42),
+				AttributeNamespace::CollectionOwner,
+				bvec![key],
+				bvec![0],
+			));
+		}
+
+		assert_noop!(
+			Nfts::evolve_item(0, 42, vec![1], vec![(vec![3], vec![1])], account(2)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::evolve_item(0, 42, vec![1], vec![(vec![3], vec![1]); 3], account(1)),
+			Error::<Test>::MaxAttributesLimitReached
+		);
+
+		assert_ok!(Nfts::evolve_item(0, 42, vec![1; 3], vec![(vec![3], vec![1])], account(1)));
+		assert_eq!(Nfts::owner(0, 42), Some(account(2)));
+		assert_eq!(ItemMetadataOf::<Test>::get(0, 42).unwrap().data, vec![1; 3]);
+		assert_eq!(
+			attributes(0),
+			vec![(Some(42), AttributeNamespace::CollectionOwner, vec![3], vec![1])]
+		);
+	});
+}
+
+#[test]
+fn schema_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_eq!(Nfts::schema(0), None);
+
+		let schema =
+			vec![(b"color".to_vec(), AttrType::Text), (b"level".to_vec(), AttrType::Number)];
+		assert_noop!(
+			Nfts::set_schema(0, schema.clone(), true, Some(account(2))),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::set_schema(0, schema.clone(), false, Some(account(1))));
+		assert_eq!(Nfts::schema(0), Some((schema.clone(), false)));
+
+		// undeclared keys and values of the wrong type are only rejected in strict mode
+		let set_attribute = |key: &[u8], value: &[u8]| {
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(42),
+				AttributeNamespace::CollectionOwner,
+				key.to_vec().try_into().unwrap(),
+				value.to_vec().try_into().unwrap(),
+			)
+		};
+		assert_ok!(set_attribute(b"size", &[0]));
+		assert_ok!(set_attribute(b"level", b"high"));
+		assert_ok!(Nfts::set_schema(0, schema.clone(), true, None));
+		assert_eq!(Nfts::schema(0), Some((schema, true)));
+		assert_noop!(set_attribute(b"shape", b"round"), Error::<Test>::UndeclaredAttribute);
+		assert_noop!(set_attribute(b"color", &[0xff]), Error::<Test>::InvalidAttributeType);
+		assert_noop!(set_attribute(b"level", b"high"), Error::<Test>::InvalidAttributeType);
+		assert_ok!(set_attribute(b"color", b"red"));
+		assert_ok!(set_attribute(b"level", b"42"));
+	});
+}
+
+#[test]
+fn mint_permission_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(Nfts::mint_permission(0), MintPermission::Closed);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_eq!(Nfts::mint_permission(0), MintPermission::Issuer);
+
+		for (mint_type, permission) in [
+			(MintType::Public, MintPermission::Public),
+			(MintType::HolderOf(1), MintPermission::HolderOf(1)),
+		] {
+			assert_ok!(Nfts::update_mint_settings(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				MintSettings { mint_type, ..Default::default() }
+			));
+			assert_eq!(Nfts::mint_permission(0), permission);
+		}
+
+		// closed outside of the mint window
+		assert_ok!(Nfts::set_mint_window(0, 2, 3, None));
+		assert_eq!(Nfts::mint_permission(0), MintPermission::Closed);
+		System::set_block_number(2);
+		assert_eq!(Nfts::mint_permission(0), MintPermission::HolderOf(1));
+		System::set_block_number(4);
+		assert_eq!(Nfts::mint_permission(0), MintPermission::Closed);
+	});
+}
+
+#[test]
+fn bound_contract_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_eq!(Nfts::bound_contract(0), None);
+
+		assert_noop!(
+			Nfts::set_bound_contract(0, account(5), Some(account(2))),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::set_bound_contract(1, account(5), Some(account(1))),
+			Error::<Test>::UnknownCollection
+		);
+		assert_ok!(Nfts::set_bound_contract(0, account(5), Some(account(1))));
+		assert_eq!(Nfts::bound_contract(0), Some(account(5)));
+
+		assert_ok!(Nfts::set_bound_contract(0, account(6), None));
+		assert_eq!(Nfts::bound_contract(0), Some(account(6)));
+	});
+}
+
+#[test]
+fn apply_transition_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+
+		assert_ok!(Nfts::apply_transition(
+			account(1),
+			vec![
+				ItemOp::Mint { collection: 0, item: 1, mint_to: account(1) },
+				ItemOp::SetAttribute {
+					collection: 0,
+					item: 1,
+					namespace: AttributeNamespace::CollectionOwner,
+					key: vec![0],
+					value: vec![1],
+				},
+				ItemOp::Transfer { collection: 0, item: 1, dest: account(2) },
+			]
+		));
+		assert_eq!(items(), vec![(account(2), 0, 1)]);
+		assert_eq!(
+			attributes(0),
+			vec![(Some(1), AttributeNamespace::CollectionOwner, vec![0], vec![1])]
+		);
+
+		// a failure mid-sequence rolls back the whole transition
+		assert_noop!(
+			Nfts::apply_transition(
+				account(1),
+				vec![
+					ItemOp::Mint { collection: 0, item: 2, mint_to: account(1) },
+					ItemOp::Burn { collection: 0, item: 1 },
+					ItemOp::Transfer { collection: 0, item: 2, dest: account(3) },
+				]
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_eq!(items(), vec![(account(2), 0, 1)]);
+
+		assert_noop!(
+			Nfts::apply_transition(account(1), vec![ItemOp::Burn { collection: 0, item: 1 }; 5]),
+			Error::<Test>::LimitExceeded
+		);
+	});
+}
+
+#[test]
+fn price_range_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		for item in 1..=4 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(2), None));
+		}
+		assert_eq!(Nfts::price_range(0, 10), None);
+
+		for (item, price) in [(1, 30), (2, 10), (3, 20)] {
+			assert_ok!(Nfts::set_price(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				item,
+				Some(price),
+				None
+			));
+		}
+		assert_eq!(Nfts::price_range(0, 10), Some((10, 30)));
+		assert_eq!(Nfts::price_range(0, 0), None);
+
+		// unlisting updates the range
+		assert_ok!(Nfts::set_price(RuntimeOrigin::signed(account(2)), 0, 1, None, None));
+		assert_eq!(Nfts::price_range(0, 10), Some((10, 20)));
+	});
+}
+
+#[test]
+fn metadata_version_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
+		assert_eq!(Nfts::metadata_version(0), 0);
+		assert_eq!(Nfts::item_metadata_version(0, 42), 0);
+
+		assert_ok!(Nfts::set_collection_metadata(RuntimeOrigin::signed(account(1)), 0, bvec![0]));
+		assert_ok!(Nfts::set_collection_metadata(RuntimeOrigin::signed(account(1)), 0, bvec![1]));
+		assert_eq!(Nfts::metadata_version(0), 2);
+		assert_ok!(Nfts::clear_collection_metadata(RuntimeOrigin::signed(account(1)), 0));
+		assert_eq!(Nfts::metadata_version(0), 3);
+
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0]));
+		assert_eq!(Nfts::item_metadata_version(0, 42), 1);
+		assert_ok!(Nfts::clear_metadata(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert_eq!(Nfts::item_metadata_version(0, 42), 2);
+		assert_eq!(Nfts::metadata_version(0), 3);
+
+		// the versions are removed with the item and the collection
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0]));
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert!(!ItemMetadataVersion::<Test>::contains_key(0, 42));
+		// an item with locked metadata keeps its config after being burned, so its metadata can
+		// still be set
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			43,
+			account(1),
+			ItemConfig {
+				settings: ItemSettings::from_disabled(ItemSetting::UnlockedMetadata.into())
+			}
+		));
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 43));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::root(), 0, 43, bvec![0]));
+		assert_eq!(Nfts::item_metadata_version(0, 43), 1);
+		let witness = Collection::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(account(1)), 0, witness));
+		assert!(!CollectionMetadataVersion::<Test>::contains_key(0));
+		assert_eq!(ItemMetadataVersion::<Test>::iter_prefix(0).count(), 0);
+	});
+}
+
+#[test]
+fn sell_item_should_work() {
+	new_test_ext().execute_with(|| {
+		let seller = account(1);
+		let buyer = account(2);
+		let creator = account(3);
+		let marketplace = account(4);
+		Balances::make_free_balance_be(&buyer, 2000);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, seller.clone(), None));
+
+		assert_noop!(
+			Nfts::set_collection_royalty(0, creator.clone(), 10_001, None),
+			Error::<Test>::InvalidBasisPoints
+		);
+		assert_noop!(
+			Nfts::set_collection_royalty(0, creator.clone(), 500, Some(account(2))),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::set_collection_royalty(0, creator.clone(), 500, Some(account(1))));
+		assert_eq!(Nfts::collection_royalty(0), Some((creator.clone(), 500)));
+
+		assert_noop!(
+			Nfts::sell_item(0, 42, buyer.clone(), seller.clone(), 1000, 250, marketplace.clone()),
+			Error::<Test>::NoPermission
+		);
+
+		// the item must be listed at or below the price, to the buyer if whitelisted
+		assert_noop!(
+			Nfts::sell_item(0, 42, seller.clone(), buyer.clone(), 1000, 250, marketplace.clone()),
+			Error::<Test>::NotForSale
+		);
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			42,
+			Some(1001),
+			Some(buyer.clone())
+		));
+		assert_noop!(
+			Nfts::sell_item(0, 42, seller.clone(), buyer.clone(), 1000, 250, marketplace.clone()),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(seller.clone()),
+			0,
+			42,
+			Some(1000),
+			Some(buyer.clone())
+		));
+		assert_noop!(
+			Nfts::sell_item(0, 42, seller.clone(), creator.clone(), 1000, 250, marketplace.clone()),
+			Error::<Test>::NoPermission
+		);
+		// royalty and marketplace fee together can't exceed the price
+		assert_noop!(
+			Nfts::sell_item(0, 42, seller.clone(), buyer.clone(), 1000, 9_501, marketplace.clone()),
+			Error::<Test>::InvalidBasisPoints
+		);
+
+		assert_ok!(Nfts::sell_item(
+			0,
+			42,
+			seller.clone(),
+			buyer.clone(),
+			1000,
+			250,
+			marketplace.clone()
+		));
+		assert_eq!(Balances::free_balance(&creator), 50);
+		assert_eq!(Balances::free_balance(&marketplace), 25);
+		assert_eq!(Balances::free_balance(&seller), 925);
+		assert_eq!(Balances::free_balance(&buyer), 1000);
+		assert_eq!(items(), vec![(buyer.clone(), 0, 42)]);
+		assert_eq!(ItemPriceOf::<Test>::get(0, 42), None);
+		assert!(events().contains(&Event::<Test>::ItemBought {
+			collection: 0,
+			item: 42,
+			price: 1000,
+			seller: seller.clone(),
+			buyer: buyer.clone(),
+		}));
+
+		// a buyer who can't pay reverts every payment and the transfer
+		let poor_buyer = account(5);
+		Balances::make_free_balance_be(&poor_buyer, 500);
+		assert_ok!(Nfts::set_price(RuntimeOrigin::signed(buyer.clone()), 0, 42, Some(1000), None));
+		assert!(Nfts::sell_item(
+			0,
+			42,
+			buyer.clone(),
+			poor_buyer.clone(),
+			1000,
+			250,
+			marketplace.clone()
+		)
+		.is_err());
+		assert_eq!(Balances::free_balance(&poor_buyer), 500);
+		assert_eq!(Balances::free_balance(&creator), 50);
+		assert_eq!(Balances::free_balance(&marketplace), 25);
+		assert_eq!(Balances::free_balance(&buyer), 1000);
+		assert_eq!(items(), vec![(buyer, 0, 42)]);
+	});
+}
+
+#[test]
+fn mint_many_into_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			CollectionConfig { max_supply: Some(3), ..default_collection_config() }
+		));
+
+		assert_eq!(
+			Nfts::mint_many_into(
+				&0,
+				&[(1, account(2)), (2, account(3))],
+				&ItemConfig::default(),
+				true
+			),
+			Ok(vec![1, 2])
+		);
+		assert_eq!(items(), vec![(account(2), 0, 1), (account(3), 0, 2)]);
+
+		// an existing item reverts the whole batch
+		assert_noop!(
+			Nfts::mint_many_into(
+				&0,
+				&[(3, account(2)), (1, account(3))],
+				&ItemConfig::default(),
+				true
+			),
+			Error::<Test>::AlreadyExists
+		);
+		// exceeding the max supply reverts the whole batch
+		assert_noop!(
+			Nfts::mint_many_into(
+				&0,
+				&[(3, account(2)), (4, account(3))],
+				&ItemConfig::default(),
+				true
+			),
+			Error::<Test>::MaxSupplyReached
+		);
+		assert_eq!(items(), vec![(account(2), 0, 1), (account(3), 0, 2)]);
+	});
+}
+
+#[test]
+fn transfer_many_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		for item in 1..=3 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(2), None));
+		}
+
+		assert_ok!(Nfts::transfer_many(&0, &[], &account(3)));
+		assert_ok!(Nfts::transfer_many(&0, &[1, 2], &account(3)));
+		assert_eq!(items(), vec![(account(2), 0, 3), (account(3), 0, 1), (account(3), 0, 2)]);
+
+		// a single transfer-disabled item aborts the whole batch
+		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(account(1)), 0, 2));
+		assert_noop!(Nfts::transfer_many(&0, &[1, 2], &account(2)), Error::<Test>::ItemLocked);
+		assert_noop!(Nfts::transfer_many(&0, &[3, 2], &account(4)), Error::<Test>::ItemLocked);
+		assert_eq!(items(), vec![(account(2), 0, 3), (account(3), 0, 1), (account(3), 0, 2)]);
+	});
+}
+
+#[test]
+fn item_count_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Nfts::item_count(&0), None);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_eq!(Nfts::item_count(&0), Some(0));
+
+		for item in 1..=3 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(2), None));
+		}
+		assert_eq!(Nfts::item_count(&0), Some(3));
+
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(2)), 0, 2));
+		assert_eq!(Nfts::item_count(&0), Some(2));
+		assert_eq!(Nfts::item_count(&1), None);
+	});
+}
+
+#[test]
+fn owned_count_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_eq!(Nfts::owned_count(&0, &account(2)), 0);
+
+		for item in 1..=3 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(2), None));
+		}
+		assert_eq!(Nfts::owned_count(&0, &account(2)), 3);
+
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 1, account(3)));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 2, account(3)));
+		assert_eq!(Nfts::owned_count(&0, &account(2)), 1);
+		assert_eq!(Nfts::owned_count(&0, &account(3)), 2);
+
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(2)), 0, 3));
+		assert_eq!(Nfts::owned_count(&0, &account(2)), 0);
+		assert_eq!(Nfts::owned_count(&1, &account(3)), 0);
+	});
+}
+
+#[test]
+fn attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 43, account(2), None));
+		assert_eq!(Nfts::attributes(&0, &42).count(), 0);
+
+		let set = |item, key: u8, value: u8| {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				item,
+				AttributeNamespace::CollectionOwner,
+				bvec![key],
+				bvec![value],
+			));
+		};
+		set(Some(42), 0, 10);
+		set(Some(42), 1, 11);
+		set(Some(42), 2, 12);
+		// attributes of the collection and of other items aren't returned
+		set(None, 3, 13);
+		set(Some(43), 4, 14);
+
+		let mut attributes: Vec<_> = Nfts::attributes(&0, &42).collect();
+		attributes.sort();
+		assert_eq!(attributes, vec![(vec![0], vec![10]), (vec![1], vec![11]), (vec![2], vec![12])]);
+	});
+}
+
+#[test]
+fn is_transfer_disabled_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_eq!(Nfts::is_transfer_disabled(&0, &42), Ok(false));
+
+		assert_ok!(Nfts::disable_transfer(&0, &42));
+		assert_eq!(Nfts::is_transfer_disabled(&0, &42), Ok(true));
+		assert_ok!(Nfts::enable_transfer(&0, &42));
+		assert_eq!(Nfts::is_transfer_disabled(&0, &42), Ok(false));
+
+		// a config-level lock isn't reported
+		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert!(!Nfts::can_transfer(&0, &42));
+		assert_eq!(Nfts::is_transfer_disabled(&0, &42), Ok(false));
+
+		assert_noop!(Nfts::is_transfer_disabled(&0, &43), Error::<Test>::UnknownItem);
+	});
+}
+
+#[test]
+fn swap_items_should_work() {
+	new_test_ext().execute_with(|| {
+		for owner in [account(1), account(2)] {
+			assert_ok!(Nfts::force_create(
+				RuntimeOrigin::root(),
+				owner,
+				default_collection_config()
+			));
+		}
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 1, account(3), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(2)), 1, 2, account(4), None));
+
+		assert_noop!(
+			Nfts::swap_items(0, 1, 1, 2, account(4), account(3)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::swap_items(0, 1, 1, 3, account(3), account(4)),
+			Error::<Test>::UnknownItem
+		);
+
+		assert_ok!(Nfts::swap_items(0, 1, 1, 2, account(3), account(4)));
+		assert_eq!(items(), vec![(account(3), 1, 2), (account(4), 0, 1)]);
+
+		// a locked item aborts the swap
+		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(account(2)), 1, 2));
+		assert_noop!(
+			Nfts::swap_items(0, 1, 1, 2, account(4), account(3)),
+			Error::<Test>::ItemLocked
+		);
+		assert_eq!(items(), vec![(account(3), 1, 2), (account(4), 0, 1)]);
+	});
+}
+
+#[test]
+fn current_supply_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			CollectionConfig { max_supply: Some(2), ..default_collection_config() }
+		));
+		assert_eq!(Nfts::max_supply(&0), Some(2));
+		assert_eq!(Nfts::current_supply(&0), Some(0));
+
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 1, account(1), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 2, account(1), None));
+		assert_eq!(Nfts::current_supply(&0), Some(2));
+		assert_noop!(
+			Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 3, account(1), None),
+			Error::<Test>::MaxSupplyReached
+		);
+		assert_eq!(Nfts::current_supply(&0), Nfts::max_supply(&0));
+
+		assert_eq!(Nfts::max_supply(&1), None);
+		assert_eq!(Nfts::current_supply(&1), None);
+	});
+}
+
+#[test]
+fn max_supply_is_none_when_unset() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_eq!(Nfts::max_supply(&0), None);
+		assert_eq!(Nfts::current_supply(&0), Some(0));
+	});
+}
+
+#[test]
+fn royalty_of_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 43, account(2), None));
+		assert_eq!(Nfts::royalty_of(&0, &42), None);
+
+		assert_noop!(
+			Nfts::set_royalty(&0, Some(&42), &account(3), 10_001),
+			Error::<Test>::InvalidBasisPoints
+		);
+		assert_noop!(
+			Nfts::set_royalty(&0, None, &account(3), 10_001),
+			Error::<Test>::InvalidBasisPoints
+		);
+
+		// item-level royalty
+		assert_ok!(Nfts::set_royalty(&0, Some(&42), &account(3), 10_000));
+		assert_eq!(Nfts::royalty_of(&0, &42), Some((account(3), 10_000)));
+		assert_eq!(Nfts::royalty_of(&0, &43), None);
+
+		// collection-level fallback
+		assert_ok!(Nfts::set_royalty(&0, None, &account(4), 250));
+		assert_eq!(Nfts::royalty_of(&0, &42), Some((account(3), 10_000)));
+		assert_eq!(Nfts::royalty_of(&0, &43), Some((account(4), 250)));
+		assert_eq!(Nfts::collection_royalty(0), Some((account(4), 250)));
+	});
+}
+
+#[test]
+fn mint_into_respects_max_supply() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			CollectionConfig { max_supply: Some(2), ..default_collection_config() }
+		));
+		for item in 1..=2 {
+			assert_ok!(Nfts::mint_into(&0, &item, &account(2), &ItemConfig::default(), true));
+		}
+		assert_eq!(Nfts::current_supply(&0), Some(2));
+
+		assert_noop!(
+			Nfts::mint_into(&0, &3, &account(2), &ItemConfig::default(), true),
+			Error::<Test>::MaxSupplyReached
+		);
+		assert_eq!(Nfts::current_supply(&0), Some(2));
+	});
+}
+
+#[test]
+fn mint_soulbound_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint_soulbound(&0, &42, &account(2), &ItemConfig::default(), true));
+		assert_eq!(items(), vec![(account(2), 0, 42)]);
+		assert!(!Nfts::can_transfer(&0, &42));
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(3)),
+			Error::<Test>::ItemLocked
+		);
+
+		// the creator may still lift the lock
+		assert_ok!(Nfts::enable_transfer(&0, &42));
+		assert!(Nfts::can_transfer(&0, &42));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
+		assert_eq!(items(), vec![(account(3), 0, 42)]);
+	});
+}
+
+#[test]
+fn pre_signed_attributes_reject_tampered_payload() {
+	new_test_ext().execute_with(|| {
+		let user_1_pair = sp_core::sr25519::Pair::from_string("//Alice", None).unwrap();
+		let user_1_signer = MultiSigner::Sr25519(user_1_pair.public());
+		let user_1 = user_1_signer.clone().into_account();
+		let user_2 = account(2);
+
+		Balances::make_free_balance_be(&user_1, 100);
+		Balances::make_free_balance_be(&user_2, 100);
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(user_1.clone()),
+			user_1.clone(),
+			collection_config_with_all_settings_enabled(),
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(user_1.clone()), 0, 0, user_2.clone(), None));
+
+		let pre_signed_data = PreSignedAttributes {
+			collection: 0,
+			item: 0,
+			attributes: vec![(vec![0], vec![1])],
+			namespace: AttributeNamespace::CollectionOwner,
+			deadline: 10000000,
+		};
+		let message = Encode::encode(&pre_signed_data);
+		let signature = MultiSignature::Sr25519(user_1_pair.sign(&message));
+
+		// the signature doesn't cover a modified value, namespace or deadline
+		let tampered_payloads = [
+			PreSignedAttributes { attributes: vec![(vec![0], vec![2])], ..pre_signed_data.clone() },
+			PreSignedAttributes {
+				namespace: AttributeNamespace::ItemOwner,
+				..pre_signed_data.clone()
+			},
+			PreSignedAttributes { deadline: 10000001, ..pre_signed_data.clone() },
+		];
+		for tampered_data in tampered_payloads {
+			assert_noop!(
+				Nfts::set_attributes_pre_signed(
+					RuntimeOrigin::signed(user_2.clone()),
+					tampered_data,
+					signature.clone(),
+					user_1.clone(),
+				),
+				Error::<Test>::WrongSignature
+			);
+		}
+
+		assert_ok!(Nfts::set_attributes_pre_signed(
+			RuntimeOrigin::signed(user_2.clone()),
+			pre_signed_data,
+			signature,
+			user_1,
+		));
+		assert_eq!(
+			attributes(0),
+			vec![(Some(0), AttributeNamespace::CollectionOwner, bvec![0], bvec![1])]
+		);
+	});
+}
+
+#[test]
+fn clear_all_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(account(1)),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		let reserved = Balances::reserved_balance(account(1));
+
+		for key in 0..5 {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![key],
+				bvec![0],
+			));
+		}
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			None,
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		// 5 item attributes and 1 collection attribute, each with a deposit of 3
+		assert_eq!(Balances::reserved_balance(account(1)), reserved + 18);
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 6);
+
+		assert_ok!(Nfts::clear_all_attributes(&0, &0, &AttributeNamespace::CollectionOwner));
+		assert_eq!(
+			attributes(0),
+			vec![(None, AttributeNamespace::CollectionOwner, bvec![0], bvec![0])]
+		);
+		assert_eq!(Balances::reserved_balance(account(1)), reserved + 3);
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 1);
+
+		// clearing an item without attributes is a no-op
+		assert_ok!(Nfts::clear_all_attributes(&0, &0, &AttributeNamespace::CollectionOwner));
+	});
+}
+
+#[test]
+fn set_role_and_clear_role_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert!(Nfts::is_freezer(&0, &account(1)));
+		assert!(!Nfts::is_freezer(&0, &account(2)));
+
+		assert_noop!(
+			Nfts::set_role(0, account(2), CollectionRole::Freezer, Some(account(2))),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::set_role(1, account(2), CollectionRole::Freezer, None),
+			Error::<Test>::UnknownCollection
+		);
+
+		assert_ok!(Nfts::set_role(0, account(2), CollectionRole::Freezer, Some(account(1))));
+		assert!(Nfts::is_freezer(&0, &account(2)));
+		assert!(!Nfts::is_freezer(&0, &account(1)));
+		assert!(Nfts::is_admin(&0, &account(1)));
+		assert!(events().contains(&Event::<Test>::TeamChanged {
+			collection: 0,
+			issuer: Some(account(1)),
+			admin: Some(account(1)),
+			freezer: Some(account(2)),
+		}));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(3), None));
+		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(account(2)), 0, 42));
+
+		assert_noop!(
+			Nfts::clear_role(0, account(2), CollectionRole::Freezer, Some(account(2))),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::clear_role(0, account(2), CollectionRole::Freezer, Some(account(1))));
+		assert!(!Nfts::is_freezer(&0, &account(2)));
+		assert!(!CollectionRoleOf::<Test>::contains_key(0, account(2)));
+		assert!(events().contains(&Event::<Test>::TeamChanged {
+			collection: 0,
+			issuer: Some(account(1)),
+			admin: Some(account(1)),
+			freezer: None,
+		}));
+
+		// the owner can't grant a role again once it was cleared
+		assert_noop!(
+			Nfts::set_role(0, account(2), CollectionRole::Freezer, Some(account(1))),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::set_team(RuntimeOrigin::signed(account(1)), 0, None, None, None));
+		assert_noop!(
+			Nfts::set_role(0, account(1), CollectionRole::Admin, Some(account(1))),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::set_role(0, account(1), CollectionRole::Admin, None));
+		assert!(Nfts::is_admin(&0, &account(1)));
+	});
+}
+
+#[test]
+fn migrate_to_v2_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		for item in 1..=3 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(2), None));
+		}
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 4, account(3), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 5, account(1), None));
+		for item in [1, 2, 5] {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(item),
+				AttributeNamespace::CollectionOwner,
+				bvec![0],
+				bvec![1],
+			));
+		}
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 5));
+
+		// recreate the state before the upgrade
+		let _ = AccountBalance::<Test>::clear(u32::MAX, None);
+		let _ = AttributeValueCount::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(1).put::<Nfts>();
+		assert_eq!(Nfts::transfers_tracked_since(), None);
+		assert_eq!(Nfts::rarity_score(0, 1), Some(4 * 1_000));
+
+		// nothing is migrated if there are more entries than the bound
+		System::set_block_number(5);
+		migration::v2::MigrateToV2::<Test, ConstU32<3>>::on_runtime_upgrade();
+		assert_eq!(Nfts::on_chain_storage_version(), 1);
+		assert_eq!(Nfts::transfers_tracked_since(), None);
+		assert_eq!(Nfts::owned_count(&0, &account(2)), 0);
+
+		System::set_block_number(10);
+		migration::v2::MigrateToV2::<Test, ConstU32<100>>::on_runtime_upgrade();
+		assert_eq!(Nfts::on_chain_storage_version(), 2);
+		assert_eq!(Nfts::transfers_tracked_since(), Some(10));
+		assert_eq!(Nfts::owned_count(&0, &account(2)), 3);
+		assert_eq!(Nfts::owned_count(&0, &account(3)), 1);
+		// the attribute of the burned item isn't counted
+		assert_eq!(Nfts::rarity_score(0, 1), Some(4 * 1_000 / 2));
+
+		// the migration doesn't run again once the storage version is updated
+		System::set_block_number(20);
+		migration::v2::MigrateToV2::<Test, ConstU32<100>>::on_runtime_upgrade();
+		assert_eq!(Nfts::transfers_tracked_since(), Some(10));
+		assert_eq!(Nfts::owned_count(&0, &account(2)), 3);
+	});
+}
@@ -89,6 +89,24 @@ pub(super) type PreSignedAttributesOf<T, I = ()> = PreSignedAttributes<
 	<T as SystemConfig>::AccountId,
 	BlockNumberFor<T>,
 >;
+/// A type alias for an operation applied as part of a state transition.
+pub(super) type ItemOpOf<T, I = ()> = ItemOp<
+	<T as Config<I>>::CollectionId,
+	<T as Config<I>>::ItemId,
+	<T as SystemConfig>::AccountId,
+>;
+
+/// A type alias for the information about a gifted item.
+pub(super) type PendingGiftFor<T> = PendingGift<<T as SystemConfig>::AccountId, BlockNumberFor<T>>;
+/// A type alias for an item exported together with its owner and metadata.
+pub(super) type ExportedItemOf<T, I = ()> =
+	(<T as Config<I>>::ItemId, <T as SystemConfig>::AccountId, Option<Vec<u8>>);
+/// A type alias for a page of exported items and the item to continue the export from.
+pub(super) type CollectionExportOf<T, I = ()> =
+	(Vec<ExportedItemOf<T, I>>, Option<<T as Config<I>>::ItemId>);
+
+/// A type alias for the `(start, end)` blocks of a collection's mint window.
+pub(super) type MintWindowOf<T> = (Option<BlockNumberFor<T>>, Option<BlockNumberFor<T>>);
 
 /// Information about a collection.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -217,6 +235,19 @@ pub struct PendingSwap<CollectionId, ItemId, ItemPriceWithDirection, Deadline> {
 	pub(super) deadline: Deadline,
 }
 
+/// Information about a gifted item held in escrow until it's claimed.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingGift<AccountId, Deadline> {
+	/// The account which gifted the item.
+	pub(super) sender: AccountId,
+	/// The account which can claim the item.
+	pub(super) recipient: AccountId,
+	/// The block from which the item can be claimed.
+	pub(super) claimable_at: Deadline,
+	/// The last block at which the item can be claimed, if any.
+	pub(super) expires_at: Option<Deadline>,
+}
+
 /// Information about the reserved attribute deposit.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct AttributeDeposit<DepositBalance, AccountId> {
@@ -307,6 +338,19 @@ pub enum MintType<CollectionId> {
 	HolderOf(CollectionId),
 }
 
+/// Who can currently mint items in a collection, see `Pallet::mint_permission`.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum MintPermission<CollectionId> {
+	/// Only an `Issuer` can mint items.
+	Issuer,
+	/// Anyone can mint items.
+	Public,
+	/// Only holders of items in the specified collection can mint items.
+	HolderOf(CollectionId),
+	/// No items can be minted, e.g. because the mint window is closed.
+	Closed,
+}
+
 /// Holds the information about minting.
 #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct MintSettings<Price, BlockNumber, CollectionId> {
@@ -363,6 +407,86 @@ pub enum PalletAttributes<CollectionId> {
 	UsedToClaim(CollectionId),
 	/// Marks an item as being restricted from transferring.
 	TransferDisabled,
+	/// The base URI of a collection, to which item IDs are appended to compose their URIs.
+	BaseUri,
+	/// The Merkle root of a committed set of items of a collection.
+	ItemSetRoot,
+	/// The attribute schema declared by a collection.
+	Schema,
+	/// The contract account a collection is bound to.
+	BoundContract,
+	/// The royalty paid on sales of an item or of a collection's items, as a
+	/// `(recipient, basis_points)` tuple.
+	Royalty,
+}
+
+/// The keys of the attributes declared in a collection's schema, along with their types.
+pub type AttributeSchema = Vec<(Vec<u8>, AttrType)>;
+
+/// The type of the values of an attribute declared in a collection's schema.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AttrType {
+	/// Arbitrary bytes.
+	Bytes,
+	/// UTF-8 encoded text.
+	Text,
+	/// An unsigned number written in ASCII decimal digits, e.g. `42`.
+	Number,
+	/// A boolean written as `true` or `false`.
+	Bool,
+}
+
+impl AttrType {
+	/// Returns whether `value` is a valid value of this type.
+	pub fn is_valid(&self, value: &[u8]) -> bool {
+		match self {
+			AttrType::Bytes => true,
+			AttrType::Text => core::str::from_utf8(value).is_ok(),
+			AttrType::Number => !value.is_empty() && value.iter().all(u8::is_ascii_digit),
+			AttrType::Bool => value == b"true" || value == b"false",
+		}
+	}
+}
+
+/// An operation on an item, applied as part of a state transition, see
+/// `Pallet::apply_transition`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum ItemOp<CollectionId, ItemId, AccountId> {
+	/// Mint an item to `mint_to`.
+	Mint { collection: CollectionId, item: ItemId, mint_to: AccountId },
+	/// Transfer an item to `dest`.
+	Transfer { collection: CollectionId, item: ItemId, dest: AccountId },
+	/// Set an attribute of an item.
+	SetAttribute {
+		collection: CollectionId,
+		item: ItemId,
+		namespace: AttributeNamespace<AccountId>,
+		key: Vec<u8>,
+		value: Vec<u8>,
+	},
+	/// Burn an item.
+	Burn { collection: CollectionId, item: ItemId },
+}
+
+/// A restriction which currently applies to an item.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum LockReason {
+	/// The item is locked by the [`Config::Locker`](crate::Config::Locker).
+	Locker,
+	/// Transferring the item was disabled at the system level.
+	TransferDisabled,
+	/// The items of the collection are non-transferable.
+	CollectionNonTransferable,
+	/// Transferring the item was locked.
+	ItemNonTransferable,
+	/// The item's metadata is locked.
+	MetadataLocked,
+	/// The item's attributes are locked.
+	AttributesLocked,
+	/// The item is held in escrow.
+	Escrowed,
+	/// The item is held as a pending gift.
+	Gifted,
 }
 
 /// Collection's configuration.
@@ -528,6 +652,10 @@ impl CollectionRoles {
 		self.0.insert(role);
 	}
 
+	pub fn remove_role(&mut self, role: CollectionRole) {
+		self.0.remove(role);
+	}
+
 	pub fn max_roles() -> u8 {
 		let all: BitFlags<CollectionRole> = BitFlags::all();
 		all.len() as u8
@@ -35,6 +35,7 @@ parameter_types! {
 	pub const NftsAttributeDepositBase: Balance = deposit(1, 0);
 	pub const NftsDepositPerByte: Balance = deposit(0, 1);
 	pub const NftsMaxDeadlineDuration: BlockNumber = 12 * 30 * DAYS;
+	pub const NftsPalletId: PalletId = PalletId(*b"py/nfts_");
 }
 
 impl pallet_nfts::Config for Runtime {
@@ -61,9 +62,11 @@ impl pallet_nfts::Config for Runtime {
 	type MaxAttributesPerCall = ConstU32<10>;
 	type MaxDeadlineDuration = NftsMaxDeadlineDuration;
 	type MaxTips = ConstU32<10>;
+	type MaxTransitionOps = ConstU32<20>;
 	type MetadataDepositBase = NftsMetadataDepositBase;
 	type OffchainPublic = <Signature as Verify>::Signer;
 	type OffchainSignature = Signature;
+	type PalletId = NftsPalletId;
 	type RuntimeEvent = RuntimeEvent;
 	type StringLimit = ConstU32<256>;
 	type ValueLimit = ConstU32<256>;
@@ -109,6 +109,7 @@ pub type Migrations = (
 	// Permanent.
 	pallet_contracts::Migration<Runtime>,
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
+	pallet_nfts::migration::v2::MigrateToV2<Runtime, ConstU32<1_000>>,
 );
 
 /// Executive: handles dispatch to the various modules.